@@ -1,4 +1,4 @@
-#![allow(unused)]
+#![allow(unused, clippy::legacy_numeric_constants)]
 
 use shakmaty::{fen::Fen, Color, FromSetup, Move, MoveList, Outcome, Position, Role, Setup};
 
@@ -0,0 +1,78 @@
+//! A deterministic regression check for the search, using moments from games by https://lichess.org/@/rubot_simple/all
+//!
+//! Each position is searched for exactly `STEPS` steps and once more until `DEPTH` is completed.
+//! The selected move and the last reached depth of the first search, as well as the amount of steps
+//! needed by the second one, are compared with `benches/regression.txt`, so changes to the search
+//! are noticed independently of the speed of the machine running this.
+//!
+//! Use `cargo bench --bench regression` to check the results and
+//! `RUBOT_BLESS=1 cargo bench --bench regression` to update them after an intended change.
+
+use rubot::{Bot, Depth, Logger, Steps};
+
+#[path = "chess.rs"]
+mod chess;
+
+use chess::Chess;
+use shakmaty::{uci::Uci, Setup};
+
+use std::{env, fs};
+
+const STEPS: u32 = 20_000;
+const DEPTH: u32 = 3;
+const EXPECTED: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/benches/regression.txt");
+
+const POSITIONS: &[&str] = &[
+    "6k1/2ppqp1p/1p2p1p1/1b6/8/r3PPPQ/5K1P/6NR b - - 3 34",
+    "2kr3r/1pp4p/p4b2/P4Rp1/1PP1p2P/8/8/2R1K3 b - - 0 32",
+    "7k/4Q3/1p2P1pr/1B1P1p1p/p2P4/P7/1P3PPP/5K2 b - - 0 34",
+    "3r2k1/1p2p2p/4p1p1/1p6/5P2/1P1P3P/P3KPP1/q1N4R b - - 1 27",
+    "rn2kbnr/p1pp1ppp/1p2pq2/3P4/2b1P3/1P6/P1P2PPP/RNBQK1NR w KQkq - 1 6",
+    "r2q1rk1/pp1n1p1p/2p3p1/3pbb2/1P2n3/P1P1Q3/1B2PPPP/RN2KBNR b KQ - 5 13",
+    "8/7k/3pp1p1/6RP/7P/P1p4K/8/8 b - - 0 52",
+    "r3k1r1/1pp2p1p/p2bP3/1P6/2P1Q3/3BP3/qB4PP/1R1R2K1 b q - 0 23",
+    "r1k3nr/3q2pp/1pp2p2/8/p2b1B2/P6P/1PP1Q1PN/3R3K w - - 1 27",
+    "rnbqkbnr/pppp1ppp/8/4p3/8/1P6/P1PPPPPP/RNBQKBNR w KQkq - 0 2",
+];
+
+fn search(fen: &str) -> String {
+    let chess = Chess::from_fen(fen);
+    let mut bot = Bot::new(chess.0.turn());
+    let mut logger = Logger::new(Steps(STEPS));
+    let action = bot.select(&chess, &mut logger).map_or_else(
+        || String::from("none"),
+        |m| Uci::from_move(&chess.0, &m).to_string(),
+    );
+    let depth = logger.depth();
+
+    // Unlike the steps of the first search, this changes if the search skips less states.
+    let mut logger = Logger::new(Depth(DEPTH));
+    bot.select(&chess, &mut logger);
+    format!("{};{};{};{}", fen, action, depth, logger.steps())
+}
+
+fn main() {
+    let results: Vec<_> = POSITIONS.iter().map(|fen| search(fen)).collect();
+    let results = results.join("\n") + "\n";
+
+    if env::var_os("RUBOT_BLESS").is_some() {
+        fs::write(EXPECTED, &results).unwrap();
+        println!("updated {}", EXPECTED);
+        return;
+    }
+
+    let expected = fs::read_to_string(EXPECTED).unwrap();
+    let mut changed = false;
+    for (actual, expected) in results.lines().zip(expected.lines()) {
+        if actual != expected {
+            println!("expected: {}\n  actual: {}", expected, actual);
+            changed = true;
+        }
+    }
+
+    assert!(
+        !changed && results.lines().count() == expected.lines().count(),
+        "search results changed, rerun with `RUBOT_BLESS=1` in case this is intended"
+    );
+    println!("all {} positions unchanged", POSITIONS.len());
+}
@@ -48,6 +48,11 @@
 //! ```
 //!
 //! - the size and layout of the board is unspecified
+#![allow(
+    clippy::doc_overindented_list_items,
+    clippy::iter_nth_zero,
+    clippy::manual_range_contains
+)]
 use std::io::{self, Write};
 use std::process;
 use std::time::Duration;
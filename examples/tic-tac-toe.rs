@@ -312,6 +312,7 @@ impl rubot::Game for Game {
     type Actions = Vec<Action>;
     type Fitness = Fitness;
 
+    #[allow(clippy::redundant_pattern_matching)]
     fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
         let mut actions = Vec::new();
         if !self.is_finished() {
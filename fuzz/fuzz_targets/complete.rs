@@ -6,18 +6,24 @@ extern crate rubot;
 use rubot::{brute::Brute, tree::Node, Bot, ToCompletion};
 
 fuzz_target!(|data: &[u8]| {
-    if data.len() >= 4 {
-        let node = Node::from_bytes(data);
-        let selected = Bot::new(true).select(&node, ToCompletion);
-        let is_best = Brute::new(true).check_if_best(&node, selected.as_ref(), std::u32::MAX);
-        if !is_best {
-            println!(
-                "Error with node: {:?}. Expected: {:?}, Actual: {:?}",
-                node,
-                Brute::new(true).select(&node, std::u32::MAX),
-                selected
-            );
-            panic!();
+    if let Some(node) = Node::from_fuzz_bytes(data) {
+        for &table in [false, true].iter() {
+            let mut bot = Bot::new(true);
+            if table {
+                bot = bot.with_transposition_table(1 << 16);
+            }
+            let selected = bot.select(&node, ToCompletion);
+            let is_best = Brute::new(true).check_if_best(&node, selected.as_ref(), std::u32::MAX);
+            if !is_best {
+                println!(
+                    "Error with node: {:?}, transposition table: {}. Expected: {:?}, Actual: {:?}",
+                    node,
+                    table,
+                    Brute::new(true).select(&node, std::u32::MAX),
+                    selected
+                );
+                panic!();
+            }
         }
     }
 });
@@ -12,9 +12,7 @@ extern crate rubot;
 use rubot::{brute::Brute, tree::Node, Bot, Depth, Logger, Steps, ToCompletion};
 
 fuzz_target!(|data: &[u8]| {
-    if data.len() >= 4 {
-        let node = Node::from_bytes(data);
-
+    if let Some(node) = Node::from_fuzz_bytes(data) {
         let (max_depth, max_steps) = {
             let mut logger = Logger::new(ToCompletion);
             Bot::new(true).select(&node, &mut logger);
@@ -12,7 +12,8 @@ where
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Action")
             .field("fitness", &self.fitness)
-            .field("path", &self.fitness)
+            .field("path", &self.path)
+            .field("terminal", &self.terminal)
             .finish()
     }
 }
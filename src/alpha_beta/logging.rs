@@ -0,0 +1,64 @@
+//! Emits the progress of a search using the [`log`][log] crate.
+//!
+//! [log]: https://docs.rs/log
+use crate::FitnessCondition;
+
+use std::time::Instant;
+
+/// Wraps the condition of a single search, logging each finished depth
+/// and a summary once the search is finished.
+pub(super) struct LogCondition<'a, U> {
+    condition: &'a mut U,
+    start: Instant,
+    steps: u64,
+    depth: u32,
+}
+
+impl<'a, U> LogCondition<'a, U> {
+    pub(super) fn new(condition: &'a mut U) -> Self {
+        Self {
+            condition,
+            start: Instant::now(),
+            steps: 0,
+            depth: 0,
+        }
+    }
+}
+
+impl<'a, F, U: FitnessCondition<F>> FitnessCondition<F> for LogCondition<'a, U> {
+    #[inline]
+    fn step(&mut self) -> bool {
+        self.steps += 1;
+        self.condition.step()
+    }
+
+    fn depth(&mut self, depth: u32, fitness: F) -> bool {
+        if depth > 0 {
+            log::debug!(
+                "finished depth {} after {} steps in {:?}",
+                depth - 1,
+                self.steps,
+                self.start.elapsed()
+            );
+        }
+        log::trace!("starting depth {}", depth);
+        self.depth = depth;
+        self.condition.depth(depth, fitness)
+    }
+
+    #[inline]
+    fn node(&mut self, depth: u32, active: bool, fitness: F) {
+        self.condition.node(depth, active, fitness)
+    }
+}
+
+impl<'a, U> Drop for LogCondition<'a, U> {
+    fn drop(&mut self) {
+        log::debug!(
+            "search stopped during depth {} after {} steps in {:?}",
+            self.depth,
+            self.steps,
+            self.start.elapsed()
+        );
+    }
+}
@@ -1,17 +1,27 @@
 //! A deterministic game bot using alpha beta pruning.
-use crate::{Game, IntoRunCondition, RunCondition};
+use crate::play::Outcome;
+use crate::{FitnessCondition, Game, IntoFitnessCondition, IntoRunCondition, ToCompletion};
 
 use tapir::Tap;
-use rayon::prelude::*;
 
 use std::cmp::{self, Reverse};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Debug};
+use std::hash::Hash;
 use std::mem;
+use std::ops::{Add, Range, Sub};
+use std::time::{Duration, Instant};
 
 mod debug;
+#[cfg(feature = "log")]
+mod logging;
+#[cfg(feature = "parallel")]
+mod parallel;
 
 /// A game bot which analyses its moves using [alpha beta pruning][ab_wiki] with [iterative deepening][id]. In case [`select`][sel] terminates
 /// before `condition` returned true, the result is always the best possible move. While this bot caches some data
-/// during computation, it does not require a lot of memory and will not store anything between different [`select`][sel] calls.
+/// during computation, it does not require a lot of memory. Apart from reusing its allocations, it only remembers
+/// the root actions of the last [`select`][sel] call, see [`last_root_actions`][lra], and the best action of each of its depths.
 ///
 /// This bot requires [`Game`][game] to be implemented for your game.
 ///
@@ -51,38 +61,727 @@ mod debug;
 /// [id]:https://en.wikipedia.org/wiki/Iterative_deepening_depth-first_search
 /// [ab_wiki]:https://en.wikipedia.org/wiki/Alpha%E2%80%93beta_pruning
 /// [sel]: struct.Bot.html#method.select
+/// [lra]: struct.Bot.html#method.last_root_actions
 /// [game]: ../trait.Game.html
 pub struct Bot<T: Game> {
     player: T::Player,
+    config: Config<T>,
+    root_actions: Vec<T::Action>,
+    best_moves: Vec<(u32, T::Action)>,
+    pruned: Vec<(T::Action, Pruned)>,
+    /// The state of the random number generator used by `TieBreak::Random`.
+    rng: u64,
+    cache: Cache<T>,
 }
 
-impl<T: Game + Sync> Bot<T>
-    where <<T as Game>::Actions as IntoIterator>::IntoIter: Send
-{
+/// The settings of a `Bot` which are changed using its builder methods.
+struct Config<T: Game> {
+    fixed_depth: Option<u32>,
+    max_depth: u32,
+    check_interval: u32,
+    opponent_model: OpponentModel,
+    tie_break: TieBreak<T>,
+    root_beam_width: Option<usize>,
+    dedup_actions: bool,
+    max_children: Option<usize>,
+    transpositions: Option<Transpositions<T>>,
+    contempt: Option<T::Fitness>,
+    draw: Option<DrawWindow<T::Fitness>>,
+    blur: Option<Blur<T::Fitness>>,
+}
+
+impl<T: Game> Clone for Config<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Game> Copy for Config<T> {}
+
+impl<T: Game> Bot<T> {
     /// Creates a new `Bot` for the given `player`.
     pub fn new(player: T::Player) -> Self {
-        Self { player }
+        Self::with_config(
+            player,
+            Config {
+                fixed_depth: None,
+                max_depth: u32::MAX,
+                check_interval: 1,
+                opponent_model: OpponentModel::Optimal,
+                tie_break: TieBreak::First,
+                root_beam_width: None,
+                dedup_actions: false,
+                max_children: None,
+                transpositions: None,
+                contempt: None,
+                draw: None,
+                blur: None,
+            },
+        )
+    }
+
+    /// The player this bot selects actions for.
+    pub(crate) fn player(&self) -> T::Player {
+        self.player
+    }
+
+    /// Returns the settings to change, forgetting the remembered results of the transposition table
+    /// as they may depend on the previous settings.
+    fn config_mut(&mut self) -> &mut Config<T> {
+        self.cache.transpositions.clear();
+        &mut self.config
+    }
+
+    fn with_config(player: T::Player, config: Config<T>) -> Self {
+        Self {
+            player,
+            config,
+            root_actions: Vec::new(),
+            best_moves: Vec::new(),
+            pruned: Vec::new(),
+            rng: match config.tie_break {
+                TieBreak::Random(seed) => seed,
+                _ => 0,
+            },
+            cache: Cache::new(),
+        }
+    }
+
+    /// Disables iterative deepening, only searching the depth which would be completed last when using
+    /// [`Depth`][depth]`(depth)`. The selected action is therefore just as good as the one selected using `Depth(depth)`.
+    ///
+    /// Iterative deepening uses the results of each depth to order the actions of the next one,
+    /// so this is usually slower. It may still be worth it for games which are expensive to search and
+    /// already have a great move ordering, as these only pay for the additional depths.
+    /// The run condition is still respected, cancelling the search returns the action with the
+    /// highest fitness according to [`Game::look_ahead`][la] in case the depth was not finished.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Depth, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 8),
+    ///         Node::new(true, 9)
+    ///     ]),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).select(&tree, Depth(1)), Some(1));
+    /// assert_eq!(Bot::new(true).with_fixed_depth(1).select(&tree, ToCompletion), Some(1));
+    /// ```
+    /// [depth]: ../struct.Depth.html
+    /// [la]: ../trait.Game.html#method.look_ahead
+    pub fn with_fixed_depth(mut self, depth: u32) -> Self {
+        self.config_mut().fixed_depth = Some(depth);
+        self
+    }
+
+    /// Never searches deeper than [`Depth`][depth]`(depth)`, regardless of the run condition.
+    ///
+    /// The search is recursive and uses stack space proportional to the current depth.
+    /// When using [`ToCompletion`][complete] on very deep game trees, this can be used
+    /// to prevent a stack overflow, treating all states at `depth` as leaves instead.
+    /// This also limits [`with_fixed_depth`][fixed].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Depth, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 8),
+    ///         Node::new(true, 9)
+    ///     ]),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).select(&tree, Depth(0)), Some(0));
+    /// assert_eq!(Bot::new(true).with_max_depth(0).select(&tree, ToCompletion), Some(0));
+    /// // Without a limit, the bot searches the complete tree.
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    /// ```
+    /// [depth]: ../struct.Depth.html
+    /// [complete]: ../struct.ToCompletion.html
+    /// [fixed]: struct.Bot.html#method.with_fixed_depth
+    pub fn with_max_depth(mut self, depth: u32) -> Self {
+        self.config_mut().max_depth = depth;
+        self
+    }
+
+    /// Only calls [`RunCondition::step`][step] once every `interval` search steps instead of at each one.
+    ///
+    /// For games where executing an action is very cheap, checking the run condition
+    /// can take a significant part of the total time. Note that this means that the search may take
+    /// up to `interval` steps longer than requested and that conditions and loggers counting steps,
+    /// like [`Steps`][steps] and [`Logger::steps`][logger], only count every `interval`th step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is `0`.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    /// use std::time::Duration;
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 8),
+    ///         Node::new(true, 9)
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true).with_check_interval(64);
+    /// assert_eq!(bot.select(&tree, Duration::from_secs(1)), Some(1));
+    /// ```
+    /// [step]: ../trait.RunCondition.html#tymethod.step
+    /// [steps]: ../struct.Steps.html
+    /// [logger]: ../struct.Logger.html#method.steps
+    pub fn with_check_interval(mut self, interval: u32) -> Self {
+        assert_ne!(interval, 0, "the check interval must not be 0");
+        self.config_mut().check_interval = interval;
+        self
+    }
+
+    /// Only searches the `width` most promising root actions once the first depth was completed,
+    /// discarding all other actions which were not yet completely searched.
+    ///
+    /// This focuses the search on the actions which looked best at shallow depths, allowing games with
+    /// a lot of possible actions to be searched deeper in the same time. This is unsound, as an action
+    /// which only looks bad at shallow depths is never searched again, so the selected action
+    /// may not be the best one, even when using [`ToCompletion`][complete].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is `0`.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 6).with_children(&[
+    ///             Node::new(false, 6),
+    ///         ]),
+    ///     ]),
+    ///     Node::new(false, 3).with_children(&[
+    ///         // This looks worse at first, but actually leads to the best state.
+    ///         Node::new(true, 2).with_children(&[
+    ///             Node::new(false, 9),
+    ///         ]),
+    ///     ]),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    /// let mut bot = Bot::new(true).with_root_beam_width(1);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    /// ```
+    /// [complete]: ../struct.ToCompletion.html
+    pub fn with_root_beam_width(mut self, width: usize) -> Self {
+        assert_ne!(width, 0, "the bot must search at least one root action");
+        self.config_mut().root_beam_width = Some(width);
+        self
+    }
+
+    /// Removes duplicate actions returned by [`Game::actions`][actions] before searching them, keeping the first one.
+    ///
+    /// Searching the same action multiple times does not change the result, but wastes time.
+    /// As comparing each action with all others is slow, this is disabled by default and should only be
+    /// used for games which can not easily prevent duplicates themselves. With the `log` feature, debug builds
+    /// warn about duplicate actions in the searched state if this is disabled.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 9),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true).with_dedup_actions(true);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// assert_eq!(bot.last_root_actions(), &[0, 1]);
+    /// ```
+    /// [actions]: ../trait.Game.html#tymethod.actions
+    pub fn with_dedup_actions(mut self, dedup: bool) -> Self {
+        self.config_mut().dedup_actions = dedup;
+        self
+    }
+
+    /// Only searches the first `k` actions of each state below the root, after sorting them in the
+    /// order in which they would be searched.
+    ///
+    /// This bounds the cost of each state for games with a huge amount of possible actions. Just like
+    /// [`with_root_beam_width`][beam], this is unsound, as the best action of a state may never be searched,
+    /// so this should only be used as a last resort. Unlike it, this applies to every state below the root,
+    /// while the root actions themselves are not limited.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is `0`.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 4).with_children(&[
+    ///             Node::new(false, 3),
+    ///             // This is searched last, as it looks worse than the first action.
+    ///             Node::new(false, 2).with_children(&[
+    ///                 Node::new(true, 9),
+    ///             ]),
+    ///         ]),
+    ///     ]),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    /// let mut bot = Bot::new(true).with_max_children(1);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    /// ```
+    /// [beam]: struct.Bot.html#method.with_root_beam_width
+    pub fn with_max_children(mut self, k: usize) -> Self {
+        assert_ne!(
+            k, 0,
+            "the bot must search at least one action of each state"
+        );
+        self.config_mut().max_children = Some(k);
+        self
+    }
+
+    /// Remembers the result of searched states, so states which are reached using different
+    /// sequences of actions, or again during the next depth, do not have to be searched another time.
+    ///
+    /// A remembered result is only reused instead of searching the state if it was searched with the same
+    /// remaining depth and its fitness is precise enough for the current search, otherwise the best action
+    /// of the remembered result is searched first. The results are kept between searches, as they only
+    /// depend on the state itself and the settings of the bot. Changing the bot using any of its `with_*`
+    /// methods therefore forgets all remembered results.
+    /// This requires every state with the same value according to [`Eq`][eq] to also have the same fitness
+    /// and possible actions. Storing each state takes some time and memory, so this is disabled by default.
+    /// Once `max_entries` states are remembered, the bot forgets at least half of them, starting with the
+    /// results which were searched with the least remaining depth, as they are the cheapest to search again.
+    /// The methods of the `parallel` feature use a new table for each thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_entries` is `0`.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "games")] {
+    /// use rubot::{Bot, Logger, ToCompletion, games::{Player, TicTacToe}};
+    ///
+    /// let mut logger = Logger::new(ToCompletion);
+    /// let fitness = Bot::new(Player::First).evaluate(&TicTacToe::new(), &mut logger);
+    ///
+    /// let mut table = Logger::new(ToCompletion);
+    /// let mut bot = Bot::new(Player::First).with_transposition_table(1 << 20);
+    /// assert_eq!(bot.evaluate(&TicTacToe::new(), &mut table), fitness);
+    /// assert!(table.steps() < logger.steps());
+    /// # }
+    /// ```
+    /// [eq]: https://doc.rust-lang.org/std/cmp/trait.Eq.html
+    pub fn with_transposition_table(mut self, max_entries: usize) -> Self
+    where
+        T: Eq + Hash,
+    {
+        assert_ne!(max_entries, 0, "the transposition table must not be empty");
+        self.config_mut().transpositions = Some(Transpositions {
+            max_entries,
+            get: |entries, state| entries.get(state),
+            insert: |entries, state, entry| {
+                entries.insert(state, entry);
+            },
+        });
+        self
+    }
+
+    /// Changes how the bot expects the opponent to play, the default is [`OpponentModel::Optimal`][opt].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, alpha_beta::OpponentModel, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 6),
+    ///         // This reply looks bad for the opponent, but actually wins.
+    ///         Node::new(true, 9).with_children(&[
+    ///             Node::new(false, -10),
+    ///         ]),
+    ///     ]),
+    ///     Node::new(false, 3),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    /// let mut bot = Bot::new(true).with_opponent_model(OpponentModel::TopK(1));
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics for `OpponentModel::TopK(0)`.
+    ///
+    /// [opt]: enum.OpponentModel.html#variant.Optimal
+    pub fn with_opponent_model(mut self, model: OpponentModel) -> Self {
+        assert_ne!(
+            model,
+            OpponentModel::TopK(0),
+            "the opponent must consider at least one reply"
+        );
+        self.config_mut().opponent_model = model;
+        self
+    }
+
+    /// Changes how the bot chooses between multiple equally good actions, the default is [`TieBreak::First`][first].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, alpha_beta::TieBreak, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 5),
+    ///         Node::new(true, 5),
+    ///     ]),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(0));
+    /// let mut bot = Bot::new(true).with_tie_break(TieBreak::Mobility);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// ```
+    /// [first]: enum.TieBreak.html#variant.First
+    pub fn with_tie_break(mut self, tie_break: TieBreak<T>) -> Self {
+        if let TieBreak::Random(seed) = tie_break {
+            self.rng = seed;
+        }
+        self.config_mut().tie_break = tie_break;
+        self
+    }
+
+    /// Treats every fitness which is at most `epsilon` away from `value` as a draw when using [`with_contempt`][contempt].
+    ///
+    /// This is meant for games with a continuous fitness, which cannot easily tell whether a state is drawn.
+    /// Without a contempt, this does not change anything.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `epsilon` is negative.
+    ///
+    /// [contempt]: struct.Bot.html#method.with_contempt
+    pub fn with_draw_value(mut self, value: T::Fitness, epsilon: T::Fitness) -> Self
+    where
+        T::Fitness: Add<Output = T::Fitness> + Sub<Output = T::Fitness>,
+    {
+        let (low, high) = (value - epsilon, value + epsilon);
+        assert!(low <= high, "the draw epsilon must not be negative");
+        self.config_mut().draw = Some(DrawWindow {
+            value,
+            low,
+            high,
+            adjusted: self.config.contempt.map_or(value, |margin| value - margin),
+        });
+        self
+    }
+
+    /// Replaces every fitness which is treated as a draw according to [`with_draw_value`][draw] with
+    /// the draw value minus `margin`.
+    ///
+    /// A positive `margin` causes the bot to avoid draws, even if this means choosing a slightly worse state,
+    /// while a negative one lets the bot prefer draws. This applies to all fitnesses of the search,
+    /// so the fitnesses returned by the bot also use the replaced value.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 1),
+    ///     Node::new(false, -2),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(0));
+    /// let mut bot = Bot::new(true).with_draw_value(0, 1).with_contempt(3);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// ```
+    /// [draw]: struct.Bot.html#method.with_draw_value
+    pub fn with_contempt(mut self, margin: T::Fitness) -> Self
+    where
+        T::Fitness: Sub<Output = T::Fitness>,
+    {
+        self.config_mut().contempt = Some(margin);
+        if let Some(draw) = self.config_mut().draw.as_mut() {
+            draw.adjusted = draw.value - margin;
+        }
+        self
+    }
+
+    /// Treats every root action whose fitness is at most `margin` worse than the best one as equally good,
+    /// so the [`TieBreak`][tb] can also choose one of them.
+    ///
+    /// Together with [`TieBreak::Random`][random], this creates a weaker bot which can not distinguish
+    /// small advantages and makes plausible mistakes, instead of playing random actions. This has no effect
+    /// when using `TieBreak::First`, which always keeps the best action, or in case the best action wins
+    /// according to [`Game::is_upper_bound`][upper]. A negative `margin` does not change anything.
+    ///
+    /// Note that the subtraction can overflow if the best fitness is close to the minimum of its type
+    /// without being a lower bound.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, alpha_beta::TieBreak, tree::Node};
+    ///
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5),
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 0),
+    /// ]);
+    ///
+    /// let selected: Vec<_> = (0..20)
+    ///     .map(|seed| {
+    ///         let mut bot = Bot::new(true)
+    ///             .with_fitness_blur(1)
+    ///             .with_tie_break(TieBreak::Random(seed));
+    ///         bot.select(&tree, ToCompletion).unwrap()
+    ///     })
+    ///     .collect();
+    /// assert!(selected.contains(&0) && selected.contains(&1));
+    /// assert!(!selected.contains(&2));
+    /// ```
+    /// [tb]: struct.Bot.html#method.with_tie_break
+    /// [random]: enum.TieBreak.html#variant.Random
+    /// [upper]: ../trait.Game.html#method.is_upper_bound
+    pub fn with_fitness_blur(mut self, margin: T::Fitness) -> Self
+    where
+        T::Fitness: Sub<Output = T::Fitness>,
+    {
+        self.config_mut().blur = Some(Blur {
+            margin,
+            sub: |fitness, margin| fitness - margin,
+        });
+        self
+    }
+
+    /// Returns the root actions considered during the last search, in the order
+    /// they were returned by [`Game::actions`][actions].
+    ///
+    /// This is empty if the bot was not the active player or there was no possible action.
+    /// In case [`evaluate`][eval] or [`evaluate_actions`][eval_act] searched multiple positions,
+    /// this only contains the actions of the last one.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(true, 4),
+    ///     Node::new(true, 7),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// assert_eq!(bot.last_root_actions(), &[0, 1]);
+    /// ```
+    /// [actions]: ../trait.Game.html#tymethod.actions
+    /// [eval]: struct.Bot.html#method.evaluate
+    /// [eval_act]: struct.Bot.html#method.evaluate_actions
+    pub fn last_root_actions(&self) -> &[T::Action] {
+        &self.root_actions
+    }
+
+    /// Returns the best action after each completed depth of the last search, starting at depth `0`.
+    ///
+    /// An action which changes between depths shows that the position is unstable, so searching
+    /// deeper is probably worth it. The selected action can differ from the last entry, for example
+    /// if a better action was found before the next depth was completed.
+    ///
+    /// Just like [`last_root_actions`][lra], this is empty if the bot did not search, and only
+    /// refers to the last searched position when using [`evaluate`][eval] or [`evaluate_actions`][eval_act].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 6).with_children(&[
+    ///             Node::new(false, 0),
+    ///         ]),
+    ///     ]),
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 4),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// // After the first depth, the first action looks better.
+    /// assert_eq!(bot.best_move_history(), &[(0, 0), (1, 1), (2, 1)]);
+    /// ```
+    /// [lra]: struct.Bot.html#method.last_root_actions
+    /// [eval]: struct.Bot.html#method.evaluate
+    /// [eval_act]: struct.Bot.html#method.evaluate_actions
+    pub fn best_move_history(&self) -> &[(u32, T::Action)] {
+        &self.best_moves
+    }
+
+    /// Returns the root actions of the last search which were not completely searched, together with the reason.
+    ///
+    /// This explains why an action which looks good was not selected. The selected action
+    /// is never part of this, while actions with an exact fitness are omitted. The actions are in
+    /// the order of [`last_root_actions`][lra]. The additional search needed for [`with_tie_break`][tb]
+    /// is ignored. This is empty if the search did not happen, for example in case there was only one possible action.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, alpha_beta::Pruned, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 9),
+    ///         // This reply proves that the action is worse, so the first reply is never searched.
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 3),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    /// assert_eq!(bot.last_pruned_actions(), &[(1, Pruned::Bound)]);
+    /// ```
+    /// [lra]: struct.Bot.html#method.last_root_actions
+    /// [tb]: struct.Bot.html#method.with_tie_break
+    pub fn last_pruned_actions(&self) -> &[(T::Action, Pruned)] {
+        &self.pruned
     }
 
     /// Returns a chosen action based on the given game state.
     ///
     /// Returns  `None` if no `Action` is possible or the bot is currently not the active player.
+    /// Use [`game_over`][over] to find out whether the game is already over in this case.
     ///
     /// This method runs until either the best possible action was found
     /// or one of `RunCondition::depth` and `RunCondition::step` returned `false`.
-    pub fn select<U: IntoRunCondition + Clone>(&mut self, state: &T, condition: U) -> Option<T::Action>
-        where <U as IntoRunCondition>::RunCondition: Send,
-    {
-        self.inner_select(state, condition)
+    ///
+    /// In case there is only one possible action, it is returned without searching
+    /// or calling `condition`.
+    ///
+    /// [over]: struct.Bot.html#method.game_over
+    pub fn select<U: IntoFitnessCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<T::Action> {
+        self.inner_select(state, &mut condition.into_fitness_condition(), false, None)
             .map(|mut act| act.path.pop().unwrap())
     }
 
+    /// Runs [`select`][sel], searching `prior` first instead of the action returned by [`Game::hint_action`][hint].
+    ///
+    /// This is useful if a good action is already known, for example from the search of a similar position.
+    /// Searching a good action first results in a better lower bound for all other actions, so the search
+    /// can skip more states. After the first depth, the best action of the previous depth is searched first instead.
+    /// `prior` is ignored if it is not a possible action.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 8),
+    ///         Node::new(true, 9)
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.select_with_prior(&tree, ToCompletion, 1), Some(1));
+    /// assert_eq!(bot.select_with_prior(&tree, ToCompletion, 7), Some(1));
+    /// ```
+    /// [sel]: struct.Bot.html#method.select
+    /// [hint]: ../trait.Game.html#method.hint_action
+    pub fn select_with_prior<U: IntoFitnessCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+        prior: T::Action,
+    ) -> Option<T::Action> {
+        self.inner_select(
+            state,
+            &mut condition.into_fitness_condition(),
+            false,
+            Some(prior),
+        )
+        .map(|mut act| act.path.pop().unwrap())
+    }
+
+    /// Runs [`select`][sel] for at most `duration` and also returns how long the search actually took.
+    ///
+    /// The returned duration can be shorter than `duration` in case the best action was found early,
+    /// for example if the game tree was searched completely or there was only one possible action.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, tree::Node};
+    /// use std::time::Duration;
+    ///
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(true, 4),
+    ///     Node::new(true, 7),
+    /// ]);
+    ///
+    /// let (action, elapsed) = Bot::new(true).select_timeout(&tree, Duration::from_secs(10));
+    /// assert_eq!(action, Some(1));
+    /// assert!(elapsed < Duration::from_secs(10));
+    /// ```
+    /// [sel]: struct.Bot.html#method.select
+    pub fn select_timeout(
+        &mut self,
+        state: &T,
+        duration: Duration,
+    ) -> (Option<T::Action>, Duration) {
+        let start = Instant::now();
+        let action = self.select(state, duration);
+        (action, start.elapsed())
+    }
+
     /// Similar to `select`, except that this function also returns the principal variation and the
     /// final evaluation of the given action.
     ///
     /// The actions are sorted in order they are executed, so
     /// `action.path[0]` is always equal to the result of `select`.
     ///
+    /// As the search is fail-soft, the fitness of the returned action is exact for the deepest
+    /// completed depth, even if other actions were cut off.
+    ///
+    /// Just like `select`, this does not search positions with only one possible action.
+    /// The returned path then only contains this action, and its fitness is the one
+    /// returned by [`Game::look_ahead`][la].
+    ///
+    /// The length of `path` is the amount of actions the bot looked ahead on the chosen line.
+    /// In case that line ends the game, for example in a forced checkmate, [`terminal`][term] is `true`.
+    ///
     /// ```rust
     /// use rubot::{Bot, ToCompletion, tree::Node};
     ///
@@ -100,110 +799,761 @@ impl<T: Game + Sync> Bot<T>
     ///     .unwrap()
     ///     .path, &[1, 0]);
     /// ```
-    pub fn detailed_select<U: IntoRunCondition + Clone>(
+    /// [la]: ../trait.Game.html#method.look_ahead
+    /// [term]: struct.Action.html#structfield.terminal
+    pub fn detailed_select<U: IntoFitnessCondition<T::Fitness>>(
         &mut self,
         state: &T,
         condition: U,
-    ) -> Option<Action<T>>
-        where <U as IntoRunCondition>::RunCondition: Send,
-    {
-        self.inner_select(state, condition)
+    ) -> Option<Action<T>> {
+        self.inner_select(state, &mut condition.into_fitness_condition(), false, None)
             .map(|act| act.tap(|act| act.path.reverse()))
     }
 
-    fn inner_select<U: IntoRunCondition + Clone>(&mut self, state: &T, condition: U) -> Option<Action<T>>
-        where <U as IntoRunCondition>::RunCondition: Send,
-    {
+    /// Returns the fitness of the given game state for the player of this bot, without caring
+    /// about the action needed to get there.
+    ///
+    /// If the bot is the active player, this uses the same search as [`select`][sel], so the returned
+    /// fitness is always the fitness of the action `select` would have chosen.
+    /// Unlike `select`, positions with only one possible action are still searched.
+    /// Otherwise every action of the opponent is evaluated in order, returning the worst resulting fitness.
+    /// The search after each of these actions is only restricted by `condition` itself, so a limited
+    /// [`Depth`][depth] applies to each action separately while a [`Duration`][dur] is shared between all of them.
+    ///
+    /// Terminal states, which do not have any possible actions, return [`Game::terminal_fitness`][tf].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Depth, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(true, 4),
+    ///     Node::new(true, 0).with_children(&[
+    ///         Node::new(true, 5),
+    ///         Node::new(true, 3),
+    ///     ])
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.evaluate(&tree, Depth(0)), Some(4));
+    /// assert_eq!(bot.evaluate(&tree, ToCompletion), Some(5));
+    /// assert_eq!(bot.evaluate(&Node::new(true, 7), ToCompletion), Some(7));
+    ///
+    /// // The opponent chooses the worst action for the bot.
+    /// let opponent = Node::new(false, 0).with_children(&[tree, Node::new(true, 2)]);
+    /// assert_eq!(bot.evaluate(&opponent, ToCompletion), Some(2));
+    /// ```
+    /// [sel]: struct.Bot.html#method.select
+    /// [depth]: ../struct.Depth.html
+    /// [dur]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    /// [tf]: ../trait.Game.html#method.terminal_fitness
+    pub fn evaluate<U: IntoFitnessCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<T::Fitness> {
+        self.root_actions.clear();
+        self.best_moves.clear();
+        self.pruned.clear();
+        self.inner_evaluate(state, &mut condition.into_fitness_condition())
+    }
+
+    /// Returns the fitness of every possible action, sorted from best to worst. Actions with the same
+    /// fitness stay in the order they were returned by [`Game::actions`][actions].
+    ///
+    /// Unlike [`select`][sel], which only knows that the other actions are worse than the chosen one,
+    /// this computes the exact fitness of each action by calling [`evaluate`][eval] on the state after it,
+    /// so this is a lot slower. All actions share `condition`, in the same way as described for `evaluate`.
+    ///
+    /// Returns an empty `Vec` if the bot is currently not the active player.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 3),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     Bot::new(true).evaluate_actions(&tree, ToCompletion),
+    ///     &[(0, 4), (2, 3), (1, 2)]
+    /// );
+    /// ```
+    /// [actions]: ../trait.Game.html#tymethod.actions
+    /// [sel]: struct.Bot.html#method.select
+    /// [eval]: struct.Bot.html#method.evaluate
+    pub fn evaluate_actions<U: IntoFitnessCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Vec<(T::Action, T::Fitness)> {
+        let mut condition = condition.into_fitness_condition();
+
+        self.root_actions.clear();
+        self.best_moves.clear();
+        self.pruned.clear();
         let (active, actions) = state.actions(self.player);
         if !active {
-            return None;
+            return Vec::new();
         }
 
-        let actions: Vec<_> = actions
+        let mut evaluated: Vec<_> = actions
             .into_iter()
-            .map(|action| (Action {
-                fitness: state.look_ahead(&action, self.player),
-                path: vec![action],
-            }, condition.clone().into_run_condition()))
+            .map(|action| {
+                let mut next = state.clone();
+                let fitness = apply_contempt(self.config.draw, next.execute(&action, self.player));
+                let fitness = self
+                    .inner_evaluate(&next, &mut condition)
+                    .unwrap_or(fitness);
+                (action, fitness)
+            })
             .collect();
+        evaluated.sort_by_key(|&(_, fitness)| Reverse(fitness));
+        evaluated
+    }
 
-        if actions.is_empty() {
-            return None;
+    /// Returns by how much the best action is better than the second best one,
+    /// using the fitnesses computed by [`evaluate_actions`][eval].
+    ///
+    /// A large margin means that the best action is obvious, while a small one
+    /// indicates that this position may deserve more time.
+    ///
+    /// Returns `None` if there are less than two possible actions or the bot is currently not the active player.
+    /// Note that the subtraction can overflow if the fitnesses are close to the limits of their type,
+    /// for example when one of the actions wins using `i32::MAX` and another one loses using `i32::MIN`.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 9),
+    ///     Node::new(false, 3),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).best_margin(&tree, ToCompletion), Some(5));
+    /// ```
+    /// [eval]: struct.Bot.html#method.evaluate_actions
+    pub fn best_margin<U: IntoFitnessCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<T::Fitness>
+    where
+        T::Fitness: Sub<Output = T::Fitness>,
+    {
+        match self.evaluate_actions(state, condition).as_slice() {
+            [(_, best), (_, second), ..] => Some(*best - *second),
+            _ => None,
         }
+    }
 
-        let mut final_actions = actions.into_par_iter().filter_map(|(a, mut condition)| {
-            let actions = vec![a];
-            let mut ctxt = Ctxt::new(state, self.player, actions);
+    /// Returns `true` if `action` is at least as good as every other possible action,
+    /// using the fitnesses computed by [`evaluate_actions`][eval].
+    ///
+    /// This is more useful than comparing the result of [`select`][sel] in tests,
+    /// as it also accepts different actions with the same fitness.
+    ///
+    /// Returns `false` if `action` is not a possible action or the bot is currently not the active player.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 9),
+    ///     Node::new(false, 9),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert!(!bot.is_best(&tree, &0, ToCompletion));
+    /// assert!(bot.is_best(&tree, &1, ToCompletion));
+    /// assert!(bot.is_best(&tree, &2, ToCompletion));
+    /// assert!(!bot.is_best(&tree, &3, ToCompletion));
+    /// ```
+    /// [eval]: struct.Bot.html#method.evaluate_actions
+    /// [sel]: struct.Bot.html#method.select
+    pub fn is_best<U: IntoFitnessCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        action: &T::Action,
+        condition: U,
+    ) -> bool {
+        let evaluated = self.evaluate_actions(state, condition);
+        match (
+            evaluated.first(),
+            evaluated.iter().find(|(a, _)| a == action),
+        ) {
+            (Some((_, best)), Some((_, fitness))) => fitness == best,
+            _ => false,
+        }
+    }
 
-            for depth in 0.. {
-                if !condition.depth(depth) {
-                    return Some(ctxt.cancel());
+    /// Searches `state` to completion and returns its proven result for the player of this bot,
+    /// together with the amount of actions until the game is over.
+    ///
+    /// The result is computed using [`Outcome::of`][of] on the final state of the line chosen by the search,
+    /// where the bot selects its actions like [`detailed_select`][sel] and its opponents choose the action with the worst
+    /// fitness for the bot, like [`evaluate`][eval]. The distance is the length of this line, so it is
+    /// not necessarily the fastest win or slowest loss. Actions of all players are counted.
+    ///
+    /// Returns `None` in case the search is limited, for example by [`with_max_depth`][max], and does not reach the end of the game.
+    /// This never returns if the game does not end.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "games")] {
+    /// use rubot::{Bot, alpha_beta::GameValue, games::{Nim, Player}};
+    ///
+    /// let nim = Nim::new(5, 3);
+    /// assert_eq!(Bot::new(Player::First).solve(&nim), Some(GameValue::ProvenWin { distance: 3 }));
+    /// assert_eq!(Bot::new(Player::Second).solve(&nim), Some(GameValue::ProvenLoss { distance: 3 }));
+    /// assert_eq!(Bot::new(Player::First).with_max_depth(0).solve(&nim), None);
+    /// # }
+    /// ```
+    /// [of]: ../play/enum.Outcome.html#method.of
+    /// [sel]: struct.Bot.html#method.detailed_select
+    /// [eval]: struct.Bot.html#method.evaluate
+    /// [max]: struct.Bot.html#method.with_max_depth
+    pub fn solve(&mut self, state: &T) -> Option<GameValue>
+    where
+        T::Player: PartialEq,
+    {
+        let mut condition = ToCompletion.into_run_condition();
+        let mut state = state.clone();
+        let mut distance = 0;
+        while !is_over(&state) && state.terminal_value(self.player).is_none() {
+            let (active, actions) = state.actions(self.player);
+            if active {
+                if let Some(action) = self.inner_select(&state, &mut condition, true, None) {
+                    if !action.terminal {
+                        return None;
+                    }
+
+                    distance += action.path.len();
+                    for action in action.path.iter().rev() {
+                        state.execute(action, self.player);
+                    }
                 }
+                break;
+            }
 
-                // Return early in case there is only one relevant action left.
-                // This is the case if we either only have one possible actions,
-                // or if all other possible actions are worse than the lower bound.
-                if let Some(exhausted) = ctxt.exhausted() {
-                    return Some(exhausted);
+            let mut worst: Option<(T::Fitness, T)> = None;
+            for action in actions {
+                let mut next = state.clone();
+                let fitness = apply_contempt(self.config.draw, next.execute(&action, self.player));
+                let fitness = self
+                    .inner_evaluate(&next, &mut condition)
+                    .unwrap_or(fitness);
+                if worst.as_ref().map_or(true, |(worst, _)| fitness < *worst) {
+                    worst = Some((fitness, next));
                 }
+            }
 
-                let mut unfinished = mem::take(&mut ctxt.unfinished);
-                // Try unfinished actions with a high expected fitness first,
-                // as they are expected to give us a better alpha value.
-                unfinished.sort_by_key(|act| Reverse(act.fitness));
+            match worst {
+                Some((_, next)) => state = next,
+                None => break,
+            }
+            distance += 1;
+        }
 
-                if let Some(best) = ctxt.best.take() {
-                    // If computation is cancelled here, we don't know anything new,
-                    // so we can just return the previous best action.
-                    if let Some(ret) = ctxt.try_action(best, depth, &mut condition, |_, act| act) {
-                        return Some(ret);
-                    }
-                }
+        Some(GameValue::of(&state, self.player, distance))
+    }
 
-                for action in unfinished.into_iter() {
-                    // In case computation is cancelled here, we may not yet have computed the best action of
-                    // the previous depth, to guard against this, we add the cancelled action back to `state.unfinished`
-                    // in case it is still empty.
-                    let on_cancel = |ctxt: &mut Ctxt<T>, act| {
-                        if ctxt.unfinished.is_empty() {
-                            ctxt.unfinished.push(act);
-                        }
-                        ctxt.cancel()
-                    };
+    /// Returns the result of `state` for the player of this bot in case the game is already over,
+    /// which is the case if there are no possible actions or [`Game::is_terminal`][term] or
+    /// [`Game::move_limit_reached`][limit] return `true`.
+    ///
+    /// This can be used to find out why [`select`][sel] returned `None`, which is also the case if
+    /// the bot is not the active player. The result is computed using [`Outcome::of`][of] and always has a distance of `0`.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "games")] {
+    /// use rubot::{Bot, Game, ToCompletion, alpha_beta::GameValue, games::{Nim, Player}};
+    ///
+    /// let mut nim = Nim::new(2, 2);
+    /// let mut bot = Bot::new(Player::Second);
+    /// assert_eq!(bot.select(&nim, ToCompletion), None);
+    /// assert_eq!(bot.game_over(&nim), None);
+    ///
+    /// nim.execute(&2, Player::First);
+    /// assert_eq!(bot.select(&nim, ToCompletion), None);
+    /// assert_eq!(bot.game_over(&nim), Some(GameValue::ProvenLoss { distance: 0 }));
+    /// # }
+    /// ```
+    /// [term]: ../trait.Game.html#method.is_terminal
+    /// [limit]: ../trait.Game.html#method.move_limit_reached
+    /// [sel]: struct.Bot.html#method.select
+    /// [of]: ../play/enum.Outcome.html#method.of
+    pub fn game_over(&self, state: &T) -> Option<GameValue>
+    where
+        T::Player: PartialEq,
+    {
+        if is_over(state) || state.actions(self.player).1.into_iter().next().is_none() {
+            Some(GameValue::of(state, self.player, 0))
+        } else {
+            None
+        }
+    }
 
-                    if let Some(ret) = ctxt.try_action(action, depth, &mut condition, on_cancel) {
-                        return Some(ret);
-                    }
+    fn inner_evaluate<U: FitnessCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: &mut U,
+    ) -> Option<T::Fitness> {
+        let draw = self.config.draw;
+        if is_over(state) {
+            return state
+                .terminal_fitness(self.player)
+                .map(|fitness| apply_contempt(draw, fitness));
+        }
+
+        if let Some(fitness) = state.terminal_value(self.player) {
+            return Some(apply_contempt(draw, fitness));
+        }
+
+        let (active, actions) = state.actions(self.player);
+        if active {
+            return match self.inner_select(state, condition, true, None) {
+                Some(action) => Some(action.fitness),
+                None => state
+                    .terminal_fitness(self.player)
+                    .map(|fitness| apply_contempt(draw, fitness)),
+            };
+        }
+
+        let player = self.player;
+        let mut replies: GameStates<T> = actions
+            .into_iter()
+            .map(|action| {
+                let mut next = state.clone();
+                let fitness = apply_contempt(draw, next.execute(&action, player));
+                (next, action, fitness)
+            })
+            .collect();
+        order_game_states(
+            state,
+            &mut replies,
+            false,
+            player,
+            self.config.opponent_model,
+            self.config.dedup_actions,
+            self.config.max_children,
+        );
+
+        let mut worst = None;
+        for (next, _, fitness) in replies {
+            let fitness = self.inner_evaluate(&next, condition).unwrap_or(fitness);
+            worst = Some(worst.map_or(fitness, |worst| cmp::min(worst, fitness)));
+        }
+
+        worst.or_else(|| {
+            state
+                .terminal_fitness(self.player)
+                .map(|fitness| apply_contempt(draw, fitness))
+        })
+    }
+
+    /// Searches `state`, only searching positions with a single possible action if `search_forced` is set.
+    /// `prior` is searched first instead of the hint of `state`.
+    fn inner_select<U: FitnessCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: &mut U,
+        search_forced: bool,
+        prior: Option<T::Action>,
+    ) -> Option<Action<T>> {
+        self.root_actions.clear();
+        self.best_moves.clear();
+        self.pruned.clear();
+        if is_over(state) {
+            return None;
+        }
+
+        let (active, actions) = state.actions(self.player);
+        if !active {
+            return None;
+        }
+
+        self.root_actions.extend(actions);
+        if self.config.dedup_actions {
+            remove_duplicates(&mut self.root_actions, |a, b| a == b);
+        } else {
+            #[cfg(all(feature = "log", debug_assertions))]
+            {
+                let actions = &self.root_actions;
+                if (1..actions.len()).any(|i| actions[..i].contains(&actions[i])) {
+                    log::warn!("`Game::actions` returned duplicate actions, consider using `Bot::with_dedup_actions`");
                 }
+            }
+        }
+        self.search_root_actions(state, condition, search_forced, prior)
+    }
 
-                // We only test partially terminated action which may still be better than the best
-                // fitness at the current depth.
-                //
-                // As the current best fitness does not come from a terminated path,
-                // we still have to keep the other partially terminated actions around,
-                // in case the best fitness of a later depth is lower.
-                for action in ctxt.relevant_partials() {
-                    // In case computation is cancelled here, we already tested at least some actions which were better than
-                    // the cancelled partial action at the previous depth, so we can use `ctxt.cancel()` without any special
-                    // considerations.
-                    if let Some(ret) =
-                        ctxt.try_action(action, depth, &mut condition, |ctxt, _| ctxt.cancel())
-                    {
-                        return Some(ret);
+    /// Searches the actions in `self.root_actions`, which must all be possible actions of `state`.
+    fn search_root_actions<U: FitnessCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: &mut U,
+        search_forced: bool,
+        prior: Option<T::Action>,
+    ) -> Option<Action<T>> {
+        let actions: Vec<_> = self
+            .root_actions
+            .iter()
+            .map(|action| Action {
+                fitness: apply_contempt(self.config.draw, state.look_ahead(action, self.player)),
+                path: vec![action.clone()],
+                terminal: false,
+                exact: false,
+                depth: None,
+            })
+            .collect();
+
+        if actions.is_empty() {
+            return None;
+        }
+
+        if actions.len() == 1 && !search_forced {
+            return actions.into_iter().next().map(|mut action| {
+                let mut next = state.clone();
+                next.execute(&action.path[0], self.player);
+                match next.terminal_value(self.player) {
+                    Some(fitness) if !is_over(&next) => {
+                        action.fitness = apply_contempt(self.config.draw, fitness);
+                        action.terminal = true;
+                    }
+                    _ => {
+                        action.terminal = is_over(&next)
+                            || next.actions(self.player).1.into_iter().next().is_none();
                     }
                 }
+                action.exact = action.terminal;
+                action
+            });
+        }
+
+        #[cfg(feature = "log")]
+        let condition = &mut logging::LogCondition::new(condition);
+        let condition = &mut TieBudget::new(condition);
+
+        let depths = self.depths();
+        let mut ctxt = Ctxt::new(state, self.player, actions, &self.config, &mut self.cache);
+
+        let player = self.player;
+        let hint = prior.or_else(|| state.hint_action(player));
+
+        ctxt.best_moves = mem::take(&mut self.best_moves);
+        ctxt.pruned = mem::take(&mut self.pruned);
+        let action = ctxt.iterative_deepening(depths, hint, self.config.root_beam_width, condition);
+        self.best_moves = mem::take(&mut ctxt.best_moves);
+        self.pruned = mem::take(&mut ctxt.pruned);
+        condition.remaining = Some(condition.steps);
+        let mut action = ctxt.break_ties(
+            &self.root_actions,
+            action,
+            self.config.tie_break,
+            self.config.blur,
+            &mut self.rng,
+            condition,
+        );
+        action.depth = ctxt.completed_depth;
+        let root_actions = &self.root_actions;
+        self.pruned
+            .retain(|(act, _)| Some(act) != action.path.last());
+        self.pruned
+            .sort_by_key(|(act, _)| root_actions.iter().position(|a| a == act));
+        debug_assert!(
+            action
+                .path
+                .last()
+                .map_or(false, |act| self.root_actions.contains(act)),
+            "selected an action which is not one of `Game::actions`, this is a bug in rubot"
+        );
+        Some(action)
+    }
+
+    /// The depths searched by `select`.
+    fn depths(&self) -> Range<u32> {
+        // Without iterative deepening, we only search the last depth searched by `Depth(fixed_depth)`.
+        match self.config.fixed_depth {
+            Some(depth) => {
+                let depth = cmp::min(depth, self.config.max_depth);
+                depth.saturating_sub(1)..depth
             }
+            None => 0..self.config.max_depth,
+        }
+    }
+}
+
+/// Sorts the actions so the most probable one is checked first and only keeps the
+/// replies considered by `opponent_model` and at most `max_children` actions.
+fn order_game_states<T: Game>(
+    game_state: &T,
+    game_states: &mut GameStates<T>,
+    active: bool,
+    player: T::Player,
+    opponent_model: OpponentModel,
+    dedup: bool,
+    max_children: Option<usize>,
+) {
+    if dedup {
+        remove_duplicates(game_states, |(_, a, _), (_, b, _)| a == b);
+    }
+
+    // This allows for faster cutoffs. Note that depending on the fitness
+    // function, this can hit some fairly bad cases.
+    if active {
+        game_states.sort_by(|(_, _, a), (_, _, b)| b.cmp(a));
+    } else {
+        game_states.sort_by_key(|(_, _, a)| *a);
+    }
+
+    if let Some(hint) = game_state.hint_action(player) {
+        move_to_front(game_states, |(_, action, _)| *action == hint);
+    }
+
+    if let (false, OpponentModel::TopK(k)) = (active, opponent_model) {
+        game_states.truncate(k);
+    }
+
+    if let Some(k) = max_children {
+        game_states.truncate(k);
+    }
+}
+
+/// Removes all elements which are the `same` as an earlier one, keeping the order of all other elements.
+fn remove_duplicates<T>(elements: &mut Vec<T>, same: impl Fn(&T, &T) -> bool) {
+    let mut i = 1;
+    while i < elements.len() {
+        if elements[..i].iter().any(|e| same(e, &elements[i])) {
+            elements.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Moves the first element matching `f` to the front, keeping the order of all other elements.
+fn move_to_front<T>(elements: &mut [T], f: impl Fn(&T) -> bool) {
+    if let Some(pos) = elements.iter().position(f) {
+        elements[..=pos].rotate_right(1);
+    }
+}
+
+/// The fitnesses which are treated as a draw, see `Bot::with_draw_value`.
+#[derive(Clone, Copy)]
+struct DrawWindow<F> {
+    value: F,
+    low: F,
+    high: F,
+    /// The fitness used instead of each fitness in `low..=high`.
+    adjusted: F,
+}
+
+/// The margin of `Bot::with_fitness_blur`.
+#[derive(Clone, Copy)]
+struct Blur<F> {
+    margin: F,
+    /// Subtracts the margin, which requires `F: Sub`.
+    sub: fn(F, F) -> F,
+}
+
+/// Replaces `fitness` in case it is treated as a draw.
+fn apply_contempt<F: Ord + Copy>(draw: Option<DrawWindow<F>>, fitness: F) -> F {
+    match draw {
+        Some(draw) if draw.low <= fitness && fitness <= draw.high => draw.adjusted,
+        _ => fitness,
+    }
+}
+
+/// Returns a random index less than `len` for `TieBreak::Random`, advancing the
+/// random number generator `state` using [SplitMix64][sm].
+///
+/// [sm]: https://prng.di.unimi.it/splitmix64.c
+fn random_index(state: &mut u64, len: usize) -> usize {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    // Multiplying instead of using `%` keeps the choice uniform.
+    ((u128::from(z) * len as u128) >> 64) as usize
+}
+
+/// Returns `true` if `state` has to be treated like a state without any possible actions.
+fn is_over<T: Game>(state: &T) -> bool {
+    state.is_terminal() || state.move_limit_reached()
+}
+
+/// How the bot expects its opponents to choose their actions, see [`Bot::with_opponent_model`][model].
+///
+/// [model]: struct.Bot.html#method.with_opponent_model
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpponentModel {
+    /// The opponent always chooses the best reply, this is regular minimax.
+    Optimal,
+    /// The opponent only chooses between its `k` best looking replies, according to the
+    /// fitness returned by [`Game::execute`][exe] and [`Game::hint_action`][hint].
+    ///
+    /// The bot then maximizes the fitness it is guaranteed to get in case the opponent plays like this, which
+    /// is useful against imperfect opponents. A returned [`Action`][act] is only `terminal` and the fitness
+    /// of [`Bot::evaluate`][eval] is only exact under this assumption.
+    ///
+    /// [exe]: ../trait.Game.html#tymethod.execute
+    /// [hint]: ../trait.Game.html#method.hint_action
+    /// [act]: struct.Action.html
+    /// [eval]: struct.Bot.html#method.evaluate
+    TopK(usize),
+}
+
+/// The proven result of a state for the player of a bot, see [`Bot::solve`][solve].
+///
+/// `distance` is the amount of actions until the game is over, where the actions of all players are counted.
+///
+/// [solve]: struct.Bot.html#method.solve
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GameValue {
+    /// The player of the bot wins.
+    ProvenWin { distance: usize },
+    /// A different player wins.
+    ProvenLoss { distance: usize },
+    /// The game ends without a winner, which is also the case if [`Game::winner`][winner] is not implemented.
+    ///
+    /// [winner]: ../trait.Game.html#method.winner
+    ProvenDraw { distance: usize },
+}
+
+impl GameValue {
+    fn of<T: Game>(state: &T, player: T::Player, distance: usize) -> Self
+    where
+        T::Player: PartialEq,
+    {
+        match Outcome::of(state) {
+            Outcome::Win(winner) if winner == player => GameValue::ProvenWin { distance },
+            Outcome::Win(_) => GameValue::ProvenLoss { distance },
+            Outcome::Draw => GameValue::ProvenDraw { distance },
+        }
+    }
+}
+
+/// Why a root action was not completely searched, see [`Bot::last_pruned_actions`][pruned].
+///
+/// [pruned]: struct.Bot.html#method.last_pruned_actions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pruned {
+    /// The action was only searched until it was proven that it is not better than the best action,
+    /// for example because this action is worse or the best action wins.
+    Bound,
+    /// The action was discarded by [`Bot::with_root_beam_width`][beam].
+    ///
+    /// [beam]: struct.Bot.html#method.with_root_beam_width
+    Beam,
+    /// The search was cancelled before the action was completely searched at the last depth.
+    Cancelled,
+}
+
+/// How the bot chooses between multiple equally good actions, see [`Bot::with_tie_break`][tb].
+///
+/// Two actions are equally good if they have the same fitness at the deepest completed depth and either
+/// both or none of them are proven. [`Bot::with_fitness_blur`][blur] additionally treats all actions
+/// which are only slightly worse than the best one as equally good. Except for `TieBreak::First`, this requires the bot to search
+/// every possible action at this depth again, which is a lot slower. Once the run condition stopped the search,
+/// this additional search may still take as many steps as the search itself. In case it takes even longer,
+/// the bot uses `TieBreak::First` instead.
+///
+/// A strictly worse action is never chosen.
+///
+/// [tb]: struct.Bot.html#method.with_tie_break
+/// [blur]: struct.Bot.html#method.with_fitness_blur
+pub enum TieBreak<T: Game> {
+    /// Selects the action which is found first. This does not require an additional search.
+    First,
+    /// Selects the action leading to the state with the most possible actions, according to [`Game::actions`][actions].
+    ///
+    /// [actions]: ../trait.Game.html#tymethod.actions
+    Mobility,
+    /// Prefers actions for which the predicate returns `true` when called with the searched state,
+    /// the action and the player of the bot, for example to prefer captures.
+    ///
+    /// The action which is found first is kept if the predicate holds for it or none of the actions.
+    /// Otherwise the first action in the order of [`Game::actions`][actions] for which it holds is selected.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, alpha_beta::TieBreak, tree::Node};
+    ///
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5),
+    ///     Node::new(false, 5),
+    ///     Node::new(false, 5),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true).with_tie_break(TieBreak::Predicate(|_, &action, _| action == 2));
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(2));
+    /// ```
+    /// [actions]: ../trait.Game.html#tymethod.actions
+    Predicate(fn(&T, &T::Action, T::Player) -> bool),
+    /// Selects one of the equally good actions at random, using a deterministic random number generator starting at the given seed.
+    ///
+    /// The generator advances each time the bot breaks a tie, so a bot playing multiple games in a row
+    /// does not always choose the same actions. Two bots using the same seed make the same choices
+    /// as long as they search the same states using the same run conditions.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, alpha_beta::TieBreak, tree::Node};
+    ///
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5),
+    ///     Node::new(false, 5),
+    ///     Node::new(false, 3),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true).with_tie_break(TieBreak::Random(42));
+    /// let selected = bot.select(&tree, ToCompletion);
+    /// assert!(selected == Some(0) || selected == Some(1));
+    ///
+    /// let mut same_seed = Bot::new(true).with_tie_break(TieBreak::Random(42));
+    /// assert_eq!(same_seed.select(&tree, ToCompletion), selected);
+    /// ```
+    Random(u64),
+}
 
-            unreachable!();
-        }).collect::<Vec<_>>();
+impl<T: Game> Clone for TieBreak<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
 
+impl<T: Game> Copy for TieBreak<T> {}
 
-        final_actions.sort_by_key(|a| Reverse((a.fitness, a.path.len())));
+impl<T: Game> Debug for TieBreak<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TieBreak::First => f.write_str("First"),
+            TieBreak::Mobility => f.write_str("Mobility"),
+            TieBreak::Predicate(_) => f.write_str("Predicate(..)"),
+            TieBreak::Random(seed) => f.debug_tuple("Random").field(seed).finish(),
+        }
+    }
+}
 
-        final_actions.into_iter().next()
+impl<T: Game> PartialEq for TieBreak<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TieBreak::First, TieBreak::First) | (TieBreak::Mobility, TieBreak::Mobility) => true,
+            (TieBreak::Random(a), TieBreak::Random(b)) => a == b,
+            (TieBreak::Predicate(a), TieBreak::Predicate(b)) => *a as usize == *b as usize,
+            _ => false,
+        }
     }
 }
 
+impl<T: Game> Eq for TieBreak<T> {}
+
 /// A top level action.
 pub struct Action<T: Game> {
     /// The current fitness of a given action.
@@ -218,11 +1568,155 @@ pub struct Action<T: Game> {
     ///
     /// This used as a stack, with `path.pop()` being the first action.
     pub path: Vec<T::Action>,
+    /// Whether `path` ends the game, so its length is the amount of actions until the game is over
+    /// during optimal play. This is `false` if the search stopped before reaching the end of this path.
+    ///
+    /// If this is `true`, `fitness` is proven, so in case it is the fitness of a draw,
+    /// neither player can improve on this draw.
+    ///
+    /// In case a proven action and one whose fitness is only an estimate of [`Game::execute`][exe]
+    /// at the searched depth are equally good, the bot always selects the proven one.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// const DRAW: i8 = 0;
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, -10),
+    ///         Node::new(true, DRAW),
+    ///     ]),
+    ///     Node::new(false, DRAW),
+    /// ]);
+    ///
+    /// let action = Bot::new(true).detailed_select(&tree, ToCompletion).unwrap();
+    /// let proven_draw = action.terminal && action.fitness == DRAW;
+    /// assert!(proven_draw);
+    /// ```
+    /// [exe]: ../trait.Game.html#tymethod.execute
+    pub terminal: bool,
+    /// Whether `fitness` is the exact fitness of this action at the deepest depth where it was completely searched.
+    ///
+    /// This is `false` if the search was cancelled before any action was searched without a cutoff,
+    /// in which case `fitness` is either only an upper bound or, if not even the first depth was
+    /// completed, the estimate of [`Game::look_ahead`][la]. The same is true for forced actions,
+    /// which are not searched at all unless they end the game.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Depth, Steps, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///     ]),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 8),
+    ///     ]),
+    /// ]);
+    ///
+    /// let action = Bot::new(true).detailed_select(&tree, Depth(1)).unwrap();
+    /// assert!(action.exact);
+    /// assert_eq!(action.fitness, 8);
+    ///
+    /// let action = Bot::new(true).detailed_select(&tree, Steps(0)).unwrap();
+    /// assert!(!action.exact);
+    /// assert_eq!(action.fitness, 7);
+    /// ```
+    /// [la]: ../trait.Game.html#method.look_ahead
+    pub exact: bool,
+    /// The deepest depth which was completely searched, starting at depth `0` like
+    /// [`best_move_history`][history].
+    ///
+    /// This is `None` if not even the first depth was completed, which is also the case for forced actions.
+    /// Once the first depth is completed, `fitness` and `path` are the result of searching this depth,
+    /// unless the search found a proven action or a better action before the next depth was completed.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Depth, Steps, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///     ]),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 8),
+    ///     ]),
+    /// ]);
+    ///
+    /// let action = Bot::new(true).detailed_select(&tree, Depth(1)).unwrap();
+    /// assert_eq!(action.depth, Some(0));
+    ///
+    /// let action = Bot::new(true).detailed_select(&tree, ToCompletion).unwrap();
+    /// assert_eq!(action.depth, Some(1));
+    ///
+    /// let action = Bot::new(true).detailed_select(&tree, Steps(0)).unwrap();
+    /// assert_eq!(action.depth, None);
+    /// ```
+    /// [history]: struct.Bot.html#method.best_move_history
+    pub depth: Option<u32>,
 }
 
 #[derive(Clone, Copy, Debug)]
 struct CancelledError;
 
+/// Wraps the condition of a search, counting its steps, so the additional
+/// search needed for the tie break can continue after `condition` stopped.
+struct TieBudget<'a, U> {
+    condition: &'a mut U,
+    steps: u64,
+    /// The steps the tie break may still take after `condition` stopped,
+    /// which is `None` until the search itself is finished.
+    remaining: Option<u64>,
+}
+
+impl<'a, U> TieBudget<'a, U> {
+    fn new(condition: &'a mut U) -> Self {
+        Self {
+            condition,
+            steps: 0,
+            remaining: None,
+        }
+    }
+}
+
+impl<'a, F, U: FitnessCondition<F>> FitnessCondition<F> for TieBudget<'a, U> {
+    #[inline]
+    fn step(&mut self) -> bool {
+        match self.remaining.as_mut() {
+            None => {
+                self.steps += 1;
+                self.condition.step()
+            }
+            Some(remaining) => {
+                if self.condition.step() {
+                    true
+                } else if *remaining > 0 {
+                    *remaining -= 1;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    fn depth(&mut self, depth: u32, fitness: F) -> bool {
+        self.condition.depth(depth, fitness)
+    }
+
+    #[inline]
+    fn node(&mut self, depth: u32, active: bool, fitness: F) {
+        self.condition.node(depth, active, fitness)
+    }
+}
+
+/// The possible next states, together with the action leading to them and their fitness.
+type GameStates<T> = Vec<(T, <T as Game>::Action, <T as Game>::Fitness)>;
+
 enum MiniMax<T: Game> {
     /// No new elements were found in this branch
     Terminated(Vec<T::Action>, Branch<T>),
@@ -259,6 +1753,9 @@ impl<T: Game> MiniMax<T> {
 
 /// A fitness and how it was calculated,
 /// this is used if we want to know whether a cutoff occurred.
+///
+/// The search is fail-soft, so in case of a cutoff the fitness is the best fitness
+/// found in this branch instead of the bound which caused the cutoff.
 enum Branch<T: Game> {
     /// `actual_fitness <= fitness`.
     ///
@@ -286,13 +1783,78 @@ impl<T: Game> Copy for Branch<T> {}
 
 impl<T: Game> Branch<T> {
     #[inline(always)]
-    fn fitness(self) -> T::Fitness {
-        match self {
+    fn fitness(&self) -> T::Fitness {
+        match *self {
             Branch::Worse(fitness) | Branch::Better(fitness) | Branch::Equal(fitness) => fitness,
         }
     }
 }
 
+/// Allocations which are reused during and between searches.
+///
+/// We create and discard a lot of paths and lists of game states, so instead
+/// of freeing them, we store them here. Everything stored here is always empty.
+/// Reusing paths causes an about 2% performance increase.
+struct Cache<T: Game> {
+    paths: Vec<Vec<T::Action>>,
+    game_states: Vec<GameStates<T>>,
+    /// The remembered results of the transposition table, see `Bot::with_transposition_table`.
+    transpositions: Table<T>,
+}
+
+impl<T: Game> Cache<T> {
+    fn new() -> Self {
+        Self {
+            paths: Vec::new(),
+            game_states: Vec::new(),
+            transpositions: HashMap::new(),
+        }
+    }
+}
+
+/// The transposition table, which remembers the result of each searched state.
+type Table<T> = HashMap<T, Entry<T>>;
+
+/// Forgets at least half of the entries of `table`, starting with the ones with the least remaining depth.
+fn forget_shallow_entries<T: Game>(table: &mut Table<T>) {
+    let mut counts = BTreeMap::new();
+    for entry in table.values() {
+        *counts.entry(entry.depth).or_insert(0) += 1;
+    }
+
+    let (len, mut forgotten) = (table.len(), 0);
+    let shallow = counts.into_iter().find(|&(_, count)| {
+        forgotten += count;
+        forgotten * 2 >= len
+    });
+    if let Some((shallow, _)) = shallow {
+        table.retain(|_, entry| entry.depth > shallow);
+    }
+}
+
+/// The result of a state stored in the transposition table.
+struct Entry<T: Game> {
+    depth: u32,
+    terminated: bool,
+    branch: Branch<T>,
+    path: Vec<T::Action>,
+}
+
+/// Accesses the transposition table, which requires `T: Hash`, so this is only created by `Bot::with_transposition_table`.
+struct Transpositions<T: Game> {
+    max_entries: usize,
+    get: for<'a> fn(&'a Table<T>, &T) -> Option<&'a Entry<T>>,
+    insert: fn(&mut Table<T>, T, Entry<T>),
+}
+
+impl<T: Game> Clone for Transpositions<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Game> Copy for Transpositions<T> {}
+
 /// The currently available data at the highest level, during minimax `State` is used instead.
 struct Ctxt<'a, T: Game> {
     /// The initial gamestate.
@@ -314,16 +1876,35 @@ struct Ctxt<'a, T: Game> {
     /// In case all paths lead to defeat, we store the action which takes the longest,
     /// so the bot doesn't start doing weird stuff once it realized it's lost.
     losing_action: Option<Action<T>>,
-    /// We create and discard a lot of paths.
-    ///
-    /// As an optimization, we therefore can reuse these paths.
-    /// The paths stored here are always empty. This causes an about
-    /// 2% performance increase.
-    path_cache: Vec<Vec<T::Action>>,
+    /// The amount of steps between each call to `FitnessCondition::step`.
+    check_interval: u32,
+    /// The amount of steps until `FitnessCondition::step` is called again.
+    until_check: u32,
+    opponent_model: OpponentModel,
+    dedup_actions: bool,
+    max_children: Option<usize>,
+    transpositions: Option<Transpositions<T>>,
+    draw: Option<DrawWindow<T::Fitness>>,
+    /// The deepest depth which was completely searched.
+    completed_depth: Option<u32>,
+    /// The best action after each completed depth.
+    best_moves: Vec<(u32, T::Action)>,
+    /// The root actions which were not completely searched.
+    pruned: Vec<(T::Action, Pruned)>,
+    /// Whether the search of a root action was cancelled.
+    cancelled: bool,
+    /// The allocations of the bot, which are reused.
+    cache: &'a mut Cache<T>,
 }
 
 impl<'a, T: Game> Ctxt<'a, T> {
-    fn new(state: &T, player: T::Player, unfinished: Vec<Action<T>>) -> Ctxt<T> {
+    fn new(
+        state: &'a T,
+        player: T::Player,
+        unfinished: Vec<Action<T>>,
+        config: &Config<T>,
+        cache: &'a mut Cache<T>,
+    ) -> Self {
         Ctxt {
             state,
             player,
@@ -332,7 +1913,30 @@ impl<'a, T: Game> Ctxt<'a, T> {
             terminated: None,
             losing_action: None,
             partially_terminated: Vec::new(),
-            path_cache: Vec::new(),
+            check_interval: config.check_interval,
+            until_check: 1,
+            opponent_model: config.opponent_model,
+            dedup_actions: config.dedup_actions,
+            max_children: config.max_children,
+            transpositions: config.transpositions,
+            draw: config.draw,
+            completed_depth: None,
+            best_moves: Vec::new(),
+            pruned: Vec::new(),
+            cancelled: false,
+            cache,
+        }
+    }
+
+    /// Calls `condition.step()` once every `self.check_interval` steps.
+    #[inline(always)]
+    fn step<U: FitnessCondition<T::Fitness>>(&mut self, condition: &mut U) -> bool {
+        self.until_check -= 1;
+        if self.until_check == 0 {
+            self.until_check = self.check_interval;
+            condition.step()
+        } else {
+            true
         }
     }
 
@@ -341,7 +1945,7 @@ impl<'a, T: Game> Ctxt<'a, T> {
     pub fn new_path(&mut self) -> Vec<T::Action> {
         // While it would be possible to create new paths using `Vec::with_capacity(depth)`
         // here, this does not actually influence the benchmarks so I decided against it.
-        self.path_cache.pop().unwrap_or_else(Vec::new)
+        self.cache.paths.pop().unwrap_or_default()
     }
 
     /// Discards a path, storing it in the cache.
@@ -349,7 +1953,37 @@ impl<'a, T: Game> Ctxt<'a, T> {
     pub fn discard_path(&mut self, mut path: Vec<T::Action>) {
         // Note that `path.clear()` does not free the allocated storage.
         path.clear();
-        self.path_cache.push(path);
+        self.cache.paths.push(path);
+    }
+
+    /// Remembers why `action` was not completely searched, forgetting the previous reason.
+    fn set_pruned(&mut self, action: &Action<T>, reason: Option<Pruned>) {
+        let root = action.path.last().expect("unexpected empty path");
+        self.pruned.retain(|(act, _)| act != root);
+        if let Some(reason) = reason {
+            self.pruned.push((root.clone(), reason));
+        }
+    }
+
+    /// Marks the root actions which were not searched because the search stopped early.
+    /// The search stops either because it was cancelled or because a winning action was found.
+    fn prune_remaining(&mut self, remaining: impl IntoIterator<Item = Action<T>>) {
+        let reason = if self.cancelled {
+            Pruned::Cancelled
+        } else {
+            Pruned::Bound
+        };
+        for action in remaining {
+            self.set_pruned(&action, Some(reason));
+            self.discard_path(action.path);
+        }
+    }
+
+    /// Discards a list of game states, storing it in the cache.
+    #[inline(always)]
+    fn discard_game_states(&mut self, mut game_states: GameStates<T>) {
+        game_states.clear();
+        self.cache.game_states.push(game_states);
     }
 
     /// Returns all partially terminated actions may be better than `self.best_unfinished`,
@@ -454,6 +2088,33 @@ impl<'a, T: Game> Ctxt<'a, T> {
             })
     }
 
+    /// Returns all root actions which were not discarded.
+    #[cfg(feature = "parallel")]
+    fn root_actions(&self) -> impl Iterator<Item = &Action<T>> {
+        self.best
+            .iter()
+            .chain(self.terminated.iter())
+            .chain(self.unfinished.iter())
+            .chain(self.partially_terminated.iter())
+            .chain(self.losing_action.iter())
+    }
+
+    /// Returns the action which would be returned by `cancel`.
+    fn current_action(&self) -> Option<&Action<T>> {
+        self.best
+            .as_ref()
+            .or(self.terminated.as_ref())
+            .or_else(|| self.unfinished.iter().max_by_key(|act| act.fitness))
+            .or(self.losing_action.as_ref())
+    }
+
+    /// Returns the fitness of the action which would be returned by `cancel`.
+    fn current_fitness(&self) -> T::Fitness {
+        self.current_action()
+            .map(|act| act.fitness)
+            .expect("no action available")
+    }
+
     fn exhausted(&mut self) -> Option<Action<T>> {
         if self.best.is_none() && self.unfinished.is_empty() {
             // We can only get partially terminated actions in
@@ -470,19 +2131,252 @@ impl<'a, T: Game> Ctxt<'a, T> {
         }
     }
 
+    /// Searches the root actions using iterative deepening, returning the selected action.
+    fn iterative_deepening<U: FitnessCondition<T::Fitness>>(
+        &mut self,
+        depths: Range<u32>,
+        mut hint: Option<T::Action>,
+        beam_width: Option<usize>,
+        condition: &mut U,
+    ) -> Action<T> {
+        let mut last_depth = None;
+        for depth in depths {
+            self.complete_depth(last_depth.replace(depth));
+            if !condition.depth(depth, self.current_fitness()) {
+                return self.cancel();
+            }
+
+            if let Some(action) = self.search_depth(depth, hint.take(), beam_width, condition) {
+                return action;
+            }
+        }
+
+        self.complete_depth(last_depth);
+        self.cancel()
+    }
+
+    /// Searches the root actions at `depth`, returning the selected action
+    /// if the search was cancelled or no greater depth has to be searched.
+    fn search_depth<U: FitnessCondition<T::Fitness>>(
+        &mut self,
+        depth: u32,
+        hint: Option<T::Action>,
+        beam_width: Option<usize>,
+        condition: &mut U,
+    ) -> Option<Action<T>> {
+        // Return early in case there is only one relevant action left.
+        // This is the case if all other possible actions are worse than the lower bound.
+        if let Some(exhausted) = self.exhausted() {
+            return Some(exhausted);
+        }
+
+        let mut unfinished = mem::take(&mut self.unfinished);
+        // Try unfinished actions with a high expected fitness first,
+        // as they are expected to give us a better alpha value.
+        unfinished.sort_by_key(|act| Reverse(act.fitness));
+        // Before the first depth is completed, the hint is the best guess we have.
+        if let Some(hint) = hint {
+            move_to_front(&mut unfinished, |act| act.path[0] == hint);
+        }
+        // Only keep the most promising actions once the first depth is completed.
+        if let (Some(width), Some(_)) = (beam_width, self.completed_depth) {
+            let width = width - self.best.is_some() as usize;
+            for action in unfinished.drain(cmp::min(width, unfinished.len())..) {
+                self.set_pruned(&action, Some(Pruned::Beam));
+                self.discard_path(action.path);
+            }
+        }
+
+        if let Some(best) = self.best.take() {
+            // If computation is cancelled here, we don't know anything new,
+            // so we can just return the previous best action.
+            if let Some(ret) = self.try_action(best, depth, condition, |_, act| act) {
+                self.prune_remaining(unfinished);
+                return Some(ret);
+            }
+        }
+
+        let mut unfinished = unfinished.into_iter();
+        while let Some(action) = unfinished.next() {
+            // In case computation is cancelled here, we may not yet have computed the best action of
+            // the previous depth, to guard against this, we add the cancelled action back to `state.unfinished`
+            // in case it is still empty.
+            let on_cancel = |ctxt: &mut Ctxt<T>, act| {
+                if ctxt.unfinished.is_empty() {
+                    ctxt.unfinished.push(act);
+                }
+                ctxt.cancel()
+            };
+
+            if let Some(ret) = self.try_action(action, depth, condition, on_cancel) {
+                self.prune_remaining(unfinished);
+                return Some(ret);
+            }
+        }
+
+        // We only test partially terminated action which may still be better than the best
+        // fitness at the current depth.
+        //
+        // As the current best fitness does not come from a terminated path,
+        // we still have to keep the other partially terminated actions around,
+        // in case the best fitness of a later depth is lower.
+        let mut partials = self.relevant_partials().into_iter();
+        while let Some(action) = partials.next() {
+            // In case computation is cancelled here, we already tested at least some actions which were better than
+            // the cancelled partial action at the previous depth, so we can use `ctxt.cancel()` without any special
+            // considerations.
+            if let Some(ret) = self.try_action(action, depth, condition, |ctxt, _| ctxt.cancel()) {
+                self.prune_remaining(partials);
+                return Some(ret);
+            }
+        }
+
+        None
+    }
+
+    /// Remembers that `depth` was completed.
+    fn complete_depth(&mut self, depth: Option<u32>) {
+        self.completed_depth = depth;
+        if let Some(depth) = depth {
+            if let Some(best) = self.current_action().and_then(|act| act.path.last()) {
+                let best = best.clone();
+                self.best_moves.push((depth, best));
+            }
+        }
+    }
+
+    /// Chooses between all root actions which are as good as `action` at the deepest completed depth.
+    ///
+    /// Returns `action` itself if it is not one of the best actions at this depth.
+    fn break_ties<U: FitnessCondition<T::Fitness>>(
+        &mut self,
+        root_actions: &[T::Action],
+        action: Action<T>,
+        tie_break: TieBreak<T>,
+        blur: Option<Blur<T::Fitness>>,
+        rng: &mut u64,
+        condition: &mut U,
+    ) -> Action<T> {
+        let depth = match (tie_break, self.completed_depth) {
+            (TieBreak::First, _) | (_, None) => return action,
+            (_, Some(depth)) => depth,
+        };
+
+        // Search each action without any cutoffs at the root, so all fitnesses are exact.
+        let mut evaluated = Vec::with_capacity(root_actions.len());
+        for root_action in root_actions {
+            let mut next = self.state.clone();
+            let fitness = apply_contempt(self.draw, next.execute(root_action, self.player));
+            let result = match self.minimax(next.clone(), depth, None, None, condition) {
+                Err(CancelledError) => return action,
+                Ok(MiniMax::DeadEnd) => Some((fitness, true, self.new_path())),
+                Ok(MiniMax::Terminated(path, Branch::Equal(fitness))) => {
+                    Some((fitness, true, path))
+                }
+                Ok(MiniMax::Open(path, Branch::Equal(fitness))) => Some((fitness, false, path)),
+                Ok(MiniMax::Terminated(path, _)) | Ok(MiniMax::Open(path, _)) => {
+                    self.discard_path(path);
+                    None
+                }
+            };
+            evaluated.extend(result.map(|(fitness, terminal, mut path)| {
+                path.push(root_action.clone());
+                (
+                    next,
+                    Action {
+                        fitness,
+                        path,
+                        terminal,
+                        exact: true,
+                        depth: None,
+                    },
+                )
+            }));
+        }
+
+        // Proven actions are preferred over estimates with the same fitness.
+        let key = |act: &Action<T>| (act.fitness, act.terminal);
+        let is_selected = |act: &Action<T>| act.path.last() == action.path.last();
+        let best = evaluated.iter().map(|(_, act)| key(act)).max();
+        let selected = evaluated.iter().find(|(_, act)| is_selected(act));
+        match (best, selected) {
+            (Some(best), Some((_, selected)))
+                if best == key(selected) && (selected.terminal || !action.terminal) => {}
+            _ => return action,
+        }
+
+        // Wins are never blurred and all actions are equally bad in case the best one loses.
+        let threshold = match (blur, best) {
+            (Some(blur), Some((fitness, _)))
+                if !self.state.is_upper_bound(fitness, self.player)
+                    && !self.state.is_lower_bound(fitness, self.player) =>
+            {
+                Some((blur.sub)(fitness, blur.margin))
+            }
+            _ => None,
+        };
+        let player = self.player;
+        let mut ties: Vec<_> = evaluated
+            .into_iter()
+            .filter(|(_, act)| {
+                Some(key(act)) == best
+                    || is_selected(act)
+                    || threshold.map_or(false, |threshold| act.fitness >= threshold)
+            })
+            .collect();
+        let chosen = match tie_break {
+            TieBreak::First => unreachable!(),
+            TieBreak::Mobility => {
+                let mut mobility = ties
+                    .iter()
+                    .map(|(next, _)| next.actions(player).1.into_iter().count());
+                let mut chosen = 0;
+                let mut most = mobility.next().unwrap();
+                for (i, count) in mobility.enumerate() {
+                    if count > most {
+                        chosen = i + 1;
+                        most = count;
+                    }
+                }
+                chosen
+            }
+            TieBreak::Random(_) => random_index(rng, ties.len()),
+            TieBreak::Predicate(predicate) => {
+                let holds =
+                    |act: &Action<T>| predicate(self.state, act.path.last().unwrap(), player);
+                let selected = ties.iter().position(|(_, act)| is_selected(act)).unwrap();
+                if holds(&ties[selected].1) {
+                    selected
+                } else {
+                    ties.iter()
+                        .position(|(_, act)| holds(act))
+                        .unwrap_or(selected)
+                }
+            }
+        };
+
+        if is_selected(&ties[chosen].1) {
+            action
+        } else {
+            self.discard_path(action.path);
+            ties.swap_remove(chosen).1
+        }
+    }
+
     /// Tests the given action at the current depth, returns `Some`
     /// once we are finished.
-    fn try_action<U: RunCondition>(
+    fn try_action<U: FitnessCondition<T::Fitness>>(
         &mut self,
         mut action: Action<T>,
         depth: u32,
         condition: &mut U,
         on_cancel: impl FnOnce(&mut Self, Action<T>) -> Action<T>,
     ) -> Option<Action<T>> {
+        self.set_pruned(&action, None);
         let mut updated_state = self.state.clone();
         let (start, rest) = action.path.split_last().expect("unexpected empty path");
 
-        let fitness = updated_state.execute(start, self.player);
+        let fitness = apply_contempt(self.draw, updated_state.execute(start, self.player));
         match self.minimax_with_path(
             rest.iter().cloned().rev(),
             updated_state,
@@ -494,8 +2388,14 @@ impl<'a, T: Game> Ctxt<'a, T> {
             None,
             condition,
         ) {
-            Err(CancelledError) => Some(on_cancel(self, action)),
+            Err(CancelledError) => {
+                self.cancelled = true;
+                self.set_pruned(&action, Some(Pruned::Cancelled));
+                Some(on_cancel(self, action))
+            }
             Ok(MiniMax::DeadEnd) => {
+                action.terminal = true;
+                action.exact = true;
                 if self.state.is_upper_bound(fitness, self.player) {
                     Some(action)
                 } else if self.state.is_lower_bound(fitness, self.player) {
@@ -505,7 +2405,9 @@ impl<'a, T: Game> Ctxt<'a, T> {
                         .map_or(true, |act| act.path.len() < action.path.len())
                     {
                         let act = self.losing_action.replace(action);
-                        act.map(|act| self.discard_path(act.path));
+                        if let Some(act) = act {
+                            self.discard_path(act.path)
+                        }
                     }
                     None
                 } else {
@@ -516,7 +2418,13 @@ impl<'a, T: Game> Ctxt<'a, T> {
             Ok(MiniMax::Terminated(mut path, Branch::Equal(fitness))) => {
                 path.push(action.path.pop().unwrap());
                 self.discard_path(action.path);
-                let action = Action { fitness, path };
+                let action = Action {
+                    fitness,
+                    path,
+                    terminal: true,
+                    exact: true,
+                    depth: None,
+                };
                 if self.state.is_upper_bound(fitness, self.player) {
                     Some(action)
                 } else if self.state.is_lower_bound(fitness, self.player) {
@@ -526,7 +2434,9 @@ impl<'a, T: Game> Ctxt<'a, T> {
                         .map_or(true, |act| act.path.len() < action.path.len())
                     {
                         let act = self.losing_action.replace(action);
-                        act.map(|act| self.discard_path(act.path));
+                        if let Some(act) = act {
+                            self.discard_path(act.path)
+                        }
                     }
                     None
                 } else {
@@ -537,21 +2447,41 @@ impl<'a, T: Game> Ctxt<'a, T> {
             Ok(MiniMax::Terminated(mut path, Branch::Worse(fitness))) => {
                 path.push(action.path.pop().unwrap());
                 self.discard_path(action.path);
-                let action = Action { fitness, path };
+                let action = Action {
+                    fitness,
+                    path,
+                    terminal: false,
+                    exact: false,
+                    depth: None,
+                };
+                self.set_pruned(&action, Some(Pruned::Bound));
                 self.add_partially_terminated(action);
                 None
             }
             Ok(MiniMax::Open(mut path, Branch::Worse(fitness))) => {
                 path.push(action.path.pop().unwrap());
                 self.discard_path(action.path);
-                let action = Action { fitness, path };
+                let action = Action {
+                    fitness,
+                    path,
+                    terminal: false,
+                    exact: false,
+                    depth: None,
+                };
+                self.set_pruned(&action, Some(Pruned::Bound));
                 self.unfinished.push(action);
                 None
             }
             Ok(MiniMax::Open(mut path, Branch::Equal(fitness))) => {
                 path.push(action.path.pop().unwrap());
                 self.discard_path(action.path);
-                let action = Action { fitness, path };
+                let action = Action {
+                    fitness,
+                    path,
+                    terminal: false,
+                    exact: true,
+                    depth: None,
+                };
                 self.add_best(action);
                 None
             }
@@ -564,27 +2494,26 @@ impl<'a, T: Game> Ctxt<'a, T> {
 
     /// Computes the next possible steps and sorts them to maximize
     /// cutoffs.
-    fn generate_game_states(&self, game_state: &T) -> (bool, Vec<(T, T::Action, T::Fitness)>) {
+    fn generate_game_states(&mut self, game_state: &T) -> (bool, GameStates<T>) {
         let (active, actions) = game_state.actions(self.player);
 
-        let mut game_states: Vec<_> = actions
-            .into_iter()
-            .map(|action| {
-                let mut game_state = game_state.clone();
-                let fitness = game_state.execute(&action, self.player);
-                (game_state, action, fitness)
-            })
-            .collect();
-
-        // Sort the actions so the most probable one is checked first.
-        // This allows for faster cutoffs. Note that depending on the fitness
-        // function, this can hit some fairly bad cases.
-        if active {
-            game_states.sort_by(|(_, _, a), (_, _, b)| b.cmp(a));
-        } else {
-            game_states.sort_by(|(_, _, a), (_, _, b)| a.cmp(b));
-        }
+        let (player, draw) = (self.player, self.draw);
+        let mut game_states = self.cache.game_states.pop().unwrap_or_default();
+        game_states.extend(actions.into_iter().map(|action| {
+            let mut game_state = game_state.clone();
+            let fitness = apply_contempt(draw, game_state.execute(&action, player));
+            (game_state, action, fitness)
+        }));
 
+        order_game_states(
+            game_state,
+            &mut game_states,
+            active,
+            player,
+            self.opponent_model,
+            self.dedup_actions,
+            self.max_children,
+        );
         (active, game_states)
     }
 
@@ -594,7 +2523,7 @@ impl<'a, T: Game> Ctxt<'a, T> {
     ///
     /// As this path is hopefully also a good choice at this depth,
     /// we very quickly get a good alpha/lower limit.
-    fn minimax_with_path<U: RunCondition>(
+    fn minimax_with_path<U: FitnessCondition<T::Fitness>>(
         &mut self,
         mut path: impl Iterator<Item = T::Action>,
         game_state: T,
@@ -603,7 +2532,7 @@ impl<'a, T: Game> Ctxt<'a, T> {
         beta: Option<T::Fitness>,
         condition: &mut U,
     ) -> Result<MiniMax<T>, CancelledError> {
-        if !condition.step() {
+        if !self.step(condition) {
             return Err(CancelledError);
         }
 
@@ -643,25 +2572,18 @@ impl<'a, T: Game> Ctxt<'a, T> {
                     .with(self, action, fitness);
 
                 if let Some(cutoff) = state.bind(self, minimax) {
+                    self.discard_game_states(game_states);
+                    visit(condition, depth, state.active, &cutoff);
                     return Ok(cutoff);
                 }
             }
             None => unreachable!("path segment not found"),
         }
 
-        for (game_state, action, fitness) in game_states {
-            let minimax = self
-                .minimax(game_state, depth - 1, state.alpha, state.beta, condition)?
-                .with(self, action, fitness);
-            if let Some(cutoff) = state.bind(self, minimax) {
-                return Ok(cutoff);
-            }
-        }
-
-        Ok(state.consume())
+        self.minimax_game_states(state, game_states, depth, condition)
     }
 
-    fn minimax<U: RunCondition>(
+    fn minimax<U: FitnessCondition<T::Fitness>>(
         &mut self,
         game_state: T,
         depth: u32,
@@ -669,14 +2591,32 @@ impl<'a, T: Game> Ctxt<'a, T> {
         beta: Option<T::Fitness>,
         condition: &mut U,
     ) -> Result<MiniMax<T>, CancelledError> {
-        if !condition.step() {
+        if !self.step(condition) {
             return Err(CancelledError);
         }
 
+        if is_over(&game_state) {
+            return Ok(MiniMax::DeadEnd);
+        }
+
+        if let Some(fitness) = game_state.terminal_value(self.player) {
+            let fitness = apply_contempt(self.draw, fitness);
+            return Ok(MiniMax::Terminated(self.new_path(), Branch::Equal(fitness)));
+        }
+
         if depth == 0 {
             let (active, actions) = game_state.actions(self.player);
-            let actions = actions.into_iter().map(|action| {
-                let fitness = game_state.look_ahead(&action, self.player);
+            let mut actions = actions.into_iter().peekable();
+            if let Some(fitness) = game_state.static_eval(self.player) {
+                if actions.peek().is_some() {
+                    let fitness = apply_contempt(self.draw, fitness);
+                    return Ok(MiniMax::Open(self.new_path(), Branch::Equal(fitness)));
+                }
+            }
+
+            let actions = actions.map(|action| {
+                let fitness =
+                    apply_contempt(self.draw, game_state.look_ahead(&action, self.player));
                 (action, fitness)
             });
             let selected = if active {
@@ -684,6 +2624,9 @@ impl<'a, T: Game> Ctxt<'a, T> {
             } else {
                 actions.min_by_key(|(_, fitness)| *fitness)
             };
+            if let Some((_, fitness)) = selected {
+                condition.node(0, active, fitness);
+            }
 
             return Ok(selected.map_or(MiniMax::DeadEnd, |(action, fitness)| {
                 let mut path = self.new_path();
@@ -692,13 +2635,40 @@ impl<'a, T: Game> Ctxt<'a, T> {
             }));
         }
 
-        let (active, game_states) = self.generate_game_states(&game_state);
+        let mut remembered = None;
+        if let Some(table) = self.transpositions {
+            if let Some(entry) = (table.get)(&self.cache.transpositions, &game_state) {
+                let precise = match entry.branch {
+                    Branch::Equal(_) => true,
+                    Branch::Worse(fitness) => alpha.map_or(false, |alpha| fitness <= alpha),
+                    Branch::Better(fitness) => beta.map_or(false, |beta| fitness >= beta),
+                };
+                // Results of other depths are only used to order the actions.
+                if precise && entry.depth == depth {
+                    let path = entry.path.clone();
+                    return Ok(if entry.terminated {
+                        MiniMax::Terminated(path, entry.branch)
+                    } else {
+                        MiniMax::Open(path, entry.branch)
+                    });
+                }
+                remembered = entry.path.last().cloned();
+            }
+        }
+
+        let (active, mut game_states) = self.generate_game_states(&game_state);
 
         if game_states.is_empty() {
+            self.discard_game_states(game_states);
             return Ok(MiniMax::DeadEnd);
         }
 
-        let mut state = State::new(
+        if let Some(remembered) = remembered {
+            move_to_front(&mut game_states, |(_, action, _)| *action == remembered);
+        }
+
+        let key = self.transpositions.map(|_| game_state.clone());
+        let state = State::new(
             self.new_path(),
             game_state,
             self.player,
@@ -706,16 +2676,67 @@ impl<'a, T: Game> Ctxt<'a, T> {
             beta,
             active,
         );
-        for (game_state, action, fitness) in game_states {
+        let minimax = self.minimax_game_states(state, game_states, depth, condition)?;
+        if let (Some(table), Some(key)) = (self.transpositions, key) {
+            let (terminated, path, branch) = match &minimax {
+                MiniMax::Terminated(path, branch) => (true, path, branch),
+                MiniMax::Open(path, branch) => (false, path, branch),
+                MiniMax::DeadEnd => unreachable!("dead end with possible actions"),
+            };
+            let entry = Entry {
+                depth,
+                terminated,
+                branch: *branch,
+                path: path.clone(),
+            };
+            if self.cache.transpositions.len() >= table.max_entries {
+                forget_shallow_entries(&mut self.cache.transpositions);
+            }
+            (table.insert)(&mut self.cache.transpositions, key, entry);
+        }
+        Ok(minimax)
+    }
+
+    /// Binds the result of each of `game_states` to `state`, until there is a cutoff.
+    fn minimax_game_states<U: FitnessCondition<T::Fitness>>(
+        &mut self,
+        mut state: State<T>,
+        mut game_states: GameStates<T>,
+        depth: u32,
+        condition: &mut U,
+    ) -> Result<MiniMax<T>, CancelledError> {
+        let mut cutoff = None;
+        for (game_state, action, fitness) in game_states.drain(..) {
             let minimax = self
                 .minimax(game_state, depth - 1, state.alpha, state.beta, condition)?
                 .with(self, action, fitness);
-            if let Some(cutoff) = state.bind(self, minimax) {
-                return Ok(cutoff);
+            cutoff = state.bind(self, minimax);
+            if cutoff.is_some() {
+                break;
             }
         }
 
-        Ok(state.consume())
+        self.discard_game_states(game_states);
+        let active = state.active;
+        let minimax = cutoff.unwrap_or_else(|| state.consume());
+        visit(condition, depth, active, &minimax);
+        Ok(minimax)
+    }
+}
+
+/// Tells `condition` about the fitness of a searched state.
+#[inline(always)]
+fn visit<T: Game, U: FitnessCondition<T::Fitness>>(
+    condition: &mut U,
+    depth: u32,
+    active: bool,
+    minimax: &MiniMax<T>,
+) {
+    match minimax {
+        MiniMax::Terminated(_, branch) | MiniMax::Open(_, branch) => {
+            condition.node(depth, active, branch.fitness())
+        }
+        MiniMax::DeadEnd => {}
     }
 }
 
@@ -809,15 +2830,9 @@ impl<T: Game> State<T> {
         };
 
         if self.terminated {
-            Some(MiniMax::Terminated(
-                mem::replace(&mut self.path, Vec::new()),
-                branch,
-            ))
+            Some(MiniMax::Terminated(std::mem::take(&mut self.path), branch))
         } else {
-            Some(MiniMax::Open(
-                mem::replace(&mut self.path, Vec::new()),
-                branch,
-            ))
+            Some(MiniMax::Open(std::mem::take(&mut self.path), branch))
         }
     }
 
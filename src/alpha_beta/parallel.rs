@@ -0,0 +1,460 @@
+//! Versions of the search methods of [`Bot`][bot] using multiple threads, enabled by the `parallel` feature.
+//!
+//! [bot]: struct.Bot.html
+use crate::{FitnessCondition, Game, IntoFitnessCondition};
+
+use rayon::prelude::*;
+
+use std::cmp::{self, Reverse};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::{apply_contempt, is_over, remove_duplicates, Action, Bot, Cache, Ctxt, TieBudget};
+
+impl<T: Game + Send + Sync> Bot<T> {
+    /// A version of [`evaluate_actions`][eval] which evaluates each action on its own thread.
+    ///
+    /// Every action uses its own clone of the run condition created from `condition`, so a
+    /// [`Duration`][dur] still limits the total time while [`Depth`][depth] and [`Steps`][steps]
+    /// apply to each action separately. This means that the result is always the same as the one
+    /// of `evaluate_actions` if both are cancelled by [`Depth`][depth] or run to completion.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 3),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(
+    ///     bot.evaluate_actions_parallel(&tree, ToCompletion),
+    ///     bot.evaluate_actions(&tree, ToCompletion),
+    /// );
+    /// ```
+    /// [eval]: struct.Bot.html#method.evaluate_actions
+    /// [dur]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    /// [depth]: ../struct.Depth.html
+    /// [steps]: ../struct.Steps.html
+    pub fn evaluate_actions_parallel<U>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Vec<(T::Action, T::Fitness)>
+    where
+        U: IntoFitnessCondition<T::Fitness>,
+        U::FitnessCondition: Clone + Send,
+    {
+        let condition = condition.into_fitness_condition();
+
+        self.root_actions.clear();
+        self.best_moves.clear();
+        self.pruned.clear();
+        let (active, actions) = state.actions(self.player);
+        if !active {
+            return Vec::new();
+        }
+
+        let jobs: Vec<_> = actions
+            .into_iter()
+            .map(|action| (action, condition.clone(), self.config))
+            .collect();
+
+        // `T::Fitness` does not have to be `Sync`, so each job owns a copy of the config.
+        let player = &self.player;
+        let mut evaluated: Vec<_> = jobs
+            .into_par_iter()
+            .map(|(action, mut condition, config)| {
+                let mut next = state.clone();
+                let fitness = apply_contempt(config.draw, next.execute(&action, *player));
+                let fitness = Bot::with_config(*player, config)
+                    .inner_evaluate(&next, &mut condition)
+                    .unwrap_or(fitness);
+                (action, fitness)
+            })
+            .collect();
+        evaluated.sort_by_key(|&(_, fitness)| Reverse(fitness));
+        evaluated
+    }
+
+    /// A version of [`select`][sel] which distributes the possible actions between `threads` threads.
+    ///
+    /// Each thread searches its actions using iterative deepening with the same settings as `self`, continuing
+    /// its search after every depth. The best action found by any thread is then selected, preferring the action
+    /// which `select` would search first in case multiple threads found equally good actions. Once the search is
+    /// finished, the [`TieBreak`][tb] of `self` chooses between all equally good actions in the same way as
+    /// for `select`, searching them again on the calling thread for at most as many steps as the busiest thread
+    /// took. Just like `select`, the search stops once a winning action is found. As the threads do not know
+    /// the best action of each other, they can skip less states than the sequential search, so this is usually
+    /// not `threads` times faster.
+    ///
+    /// `condition` is only asked whether to search the next depth on the calling thread, using the fitness of the
+    /// selected action. Each thread uses its own clone of `condition` for every step and all threads stop once
+    /// any of them is cancelled, in which case the unfinished depth is ignored. This means that a [`Duration`][dur]
+    /// stops all threads at roughly the same time, while [`Steps`][steps] applies to each thread separately.
+    ///
+    /// Using a single thread is the same as calling `select`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is `0`.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.select_parallel(&tree, ToCompletion, 2), Some(2));
+    /// assert_eq!(bot.select_parallel(&tree, ToCompletion, 4), Some(2));
+    /// ```
+    /// [sel]: struct.Bot.html#method.select
+    /// [tb]: enum.TieBreak.html
+    /// [dur]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    /// [steps]: ../struct.Steps.html
+    pub fn select_parallel<U>(
+        &mut self,
+        state: &T,
+        condition: U,
+        threads: usize,
+    ) -> Option<T::Action>
+    where
+        U: IntoFitnessCondition<T::Fitness>,
+        U::FitnessCondition: Clone + Send,
+        T::Player: Send,
+    {
+        assert_ne!(threads, 0, "`select_parallel` requires at least one thread");
+        let mut condition = condition.into_fitness_condition();
+
+        self.root_actions.clear();
+        if !is_over(state) {
+            let (active, actions) = state.actions(self.player);
+            if active {
+                self.root_actions.extend(actions);
+            }
+        }
+        if self.config.dedup_actions {
+            remove_duplicates(&mut self.root_actions, |a, b| a == b);
+        }
+        // Positions with a single possible action are not searched.
+        if threads == 1 || self.root_actions.len() < 2 {
+            return self
+                .inner_select(state, &mut condition, false, None)
+                .map(|mut act| act.path.pop().unwrap());
+        }
+        self.best_moves.clear();
+        self.pruned.clear();
+
+        let (player, config) = (self.player, self.config);
+        let unsearched = |action: &T::Action| Action {
+            fitness: apply_contempt(config.draw, state.look_ahead(action, player)),
+            path: vec![action.clone()],
+            terminal: false,
+            exact: false,
+            depth: None,
+        };
+        let threads = cmp::min(threads, self.root_actions.len());
+        let hint = state.hint_action(player);
+        let mut caches: Vec<_> = (0..threads).map(|_| Cache::new()).collect();
+        let mut workers: Vec<_> = caches
+            .iter_mut()
+            .enumerate()
+            .map(|(i, cache)| {
+                let actions = self
+                    .root_actions
+                    .iter()
+                    .skip(i)
+                    .step_by(threads)
+                    .map(unsearched)
+                    .collect();
+                Worker {
+                    ctxt: Ctxt::new(state, player, actions, &config, cache),
+                    condition: condition.clone(),
+                    hint: hint.clone(),
+                    depth: None,
+                    previous: Vec::new(),
+                    finished: None,
+                    steps: 0,
+                }
+            })
+            .collect();
+
+        // Until the first depth is completed, `select` uses the last action with the highest expected fitness.
+        let fallback = self
+            .root_actions
+            .iter()
+            .map(unsearched)
+            .max_by_key(|act| act.fitness)
+            .unwrap();
+        let mut fitness = fallback.fitness;
+        let mut selected: Option<Action<T>> = None;
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("unable to create the threads of `select_parallel`");
+        let stop = AtomicBool::new(false);
+        let mut completed_depth = None;
+        for depth in self.depths() {
+            if !condition.depth(depth, fitness) {
+                break;
+            }
+
+            let beam_width = config.root_beam_width;
+            pool.install(|| {
+                workers
+                    .par_iter_mut()
+                    .filter(|worker| worker.finished.is_none())
+                    .for_each(|worker| worker.search(depth, beam_width, &stop))
+            });
+            if stop.load(Ordering::Relaxed) {
+                break;
+            }
+
+            let action = self.first_action(&workers, selected.as_ref());
+            fitness = action.fitness;
+            selected = Some(clone_action(action));
+            self.pruned.clear();
+            for worker in workers.iter() {
+                self.pruned.extend(worker.ctxt.pruned.iter().cloned());
+            }
+
+            // Just like `select`, stop without completing this depth once a winning action is found
+            // or once there is nothing left to search.
+            if action.terminal && state.is_upper_bound(fitness, player)
+                || workers.iter().all(|worker| worker.finished.is_some())
+            {
+                break;
+            }
+            self.best_moves
+                .push((depth, action.path.last().unwrap().clone()));
+            completed_depth = Some(depth);
+        }
+
+        let action = match selected {
+            Some(action) => action,
+            None => return fallback.path.last().cloned(),
+        };
+        // The tie break may take as many steps as the thread which took the most steps.
+        let steps = workers.iter().map(|worker| worker.steps).max().unwrap_or(0);
+        let mut condition = TieBudget::new(&mut condition);
+        condition.remaining = Some(steps);
+        let mut ctxt = Ctxt::new(state, player, Vec::new(), &config, &mut self.cache);
+        ctxt.completed_depth = completed_depth;
+        let action = ctxt.break_ties(
+            &self.root_actions,
+            action,
+            config.tie_break,
+            config.blur,
+            &mut self.rng,
+            &mut condition,
+        );
+
+        let root_actions = &self.root_actions;
+        self.pruned
+            .retain(|(act, _)| Some(act) != action.path.last());
+        self.pruned
+            .sort_by_key(|(act, _)| root_actions.iter().position(|a| a == act));
+        action.path.last().cloned()
+    }
+
+    /// Returns the best action of all `workers`. In case multiple actions are equally good, this returns the one
+    /// which `select` would have found first.
+    ///
+    /// `select` does not search actions which were proven at a previous depth again, so they are found first,
+    /// followed by the `previous` action and the other actions ordered by their fitness at the previous depth.
+    /// All later actions which are as good as the first one are only known to not be better.
+    fn first_action<'a, U>(
+        &self,
+        workers: &'a [Worker<'_, T, U>],
+        previous: Option<&Action<T>>,
+    ) -> &'a Action<T> {
+        let previous = previous.and_then(|act| act.path.last());
+        let root_actions = &self.root_actions;
+        workers
+            .iter()
+            .map(|worker| {
+                let action = worker.action();
+                let root = action.path.last().unwrap();
+                let (fitness, proven) = worker
+                    .previous
+                    .iter()
+                    .find(|(act, _, _)| act == root)
+                    .map_or((None, false), |&(_, fitness, terminal)| {
+                        (Some(fitness), terminal)
+                    });
+                let position = root_actions.iter().position(|a| a == root);
+                let key = (
+                    Reverse(action.fitness),
+                    !proven,
+                    Some(root) != previous,
+                    Reverse(fitness),
+                    position,
+                );
+                (key, action)
+            })
+            .min_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, action)| action)
+            .expect("`select_parallel` without any threads")
+    }
+
+    /// Runs [`select`][sel] for each of `states`, distributing them across multiple threads.
+    ///
+    /// This is useful to analyse a lot of independent positions, for example to label a dataset.
+    /// Each state uses its own bot with the same settings as `self` and its own clone of the run
+    /// condition created from `condition`, in the same way as described for
+    /// [`evaluate_actions_parallel`][eval]. The results are in the same order as `states`.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let states = [
+    ///     Node::root().with_children(&[
+    ///         Node::new(false, 4),
+    ///         Node::new(false, 7),
+    ///     ]),
+    ///     Node::root().with_children(&[
+    ///         Node::new(false, 3),
+    ///         Node::new(false, 2),
+    ///     ]),
+    ///     Node::new(false, 0),
+    /// ];
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.select_batch(&states, ToCompletion), &[Some(1), Some(0), None]);
+    /// ```
+    /// [sel]: struct.Bot.html#method.select
+    /// [eval]: struct.Bot.html#method.evaluate_actions_parallel
+    pub fn select_batch<U>(&mut self, states: &[T], condition: U) -> Vec<Option<T::Action>>
+    where
+        U: IntoFitnessCondition<T::Fitness>,
+        U::FitnessCondition: Clone + Send,
+    {
+        let condition = condition.into_fitness_condition();
+
+        self.root_actions.clear();
+        self.best_moves.clear();
+        self.pruned.clear();
+        let player = &self.player;
+        states
+            .par_iter()
+            .map_with((condition, self.config), |(condition, config), state| {
+                Bot::with_config(*player, *config)
+                    .inner_select(state, &mut condition.clone(), false, None)
+                    .map(|mut act| act.path.pop().unwrap())
+            })
+            .collect()
+    }
+}
+
+/// Clones `action`, which is needed to remember the result of each depth of [`select_parallel`][sel].
+///
+/// [sel]: struct.Bot.html#method.select_parallel
+fn clone_action<T: Game>(action: &Action<T>) -> Action<T> {
+    Action {
+        fitness: action.fitness,
+        path: action.path.clone(),
+        terminal: action.terminal,
+        exact: action.exact,
+        depth: action.depth,
+    }
+}
+
+/// The actions searched by one thread of [`select_parallel`][sel], which continues its search after every depth.
+///
+/// [sel]: struct.Bot.html#method.select_parallel
+struct Worker<'a, T: Game, U> {
+    ctxt: Ctxt<'a, T>,
+    condition: U,
+    hint: Option<T::Action>,
+    /// The last searched depth.
+    depth: Option<u32>,
+    /// The fitness of each action before the last depth was searched and whether it was proven.
+    previous: Vec<(T::Action, T::Fitness, bool)>,
+    /// The selected action once the search is finished, so there is no need to search a greater depth.
+    finished: Option<Action<T>>,
+    /// The amount of steps taken by this thread.
+    steps: u64,
+}
+
+impl<T: Game, U: FitnessCondition<T::Fitness>> Worker<'_, T, U> {
+    /// Searches the actions of this worker at `depth`, stopping once `stop` is set.
+    fn search(&mut self, depth: u32, beam_width: Option<usize>, stop: &AtomicBool) {
+        self.previous.clear();
+        self.previous.extend(
+            self.ctxt
+                .root_actions()
+                .map(|act| (act.path.last().unwrap().clone(), act.fitness, act.terminal)),
+        );
+        self.ctxt.complete_depth(self.depth.replace(depth));
+
+        let mut condition = Round {
+            condition: &mut self.condition,
+            stop,
+            steps: &mut self.steps,
+        };
+        let hint = self.hint.take();
+        if let Some(action) = self
+            .ctxt
+            .search_depth(depth, hint, beam_width, &mut condition)
+        {
+            if !stop.load(Ordering::Relaxed) {
+                self.finished = Some(action);
+            }
+        }
+    }
+}
+
+impl<T: Game, U> Worker<'_, T, U> {
+    /// The selected action after the last searched depth.
+    fn action(&self) -> &Action<T> {
+        self.finished
+            .as_ref()
+            .or_else(|| self.ctxt.current_action())
+            .expect("no action available")
+    }
+}
+
+/// The run condition of a `Worker`, which stops once any worker is cancelled.
+struct Round<'a, U> {
+    condition: &'a mut U,
+    stop: &'a AtomicBool,
+    steps: &'a mut u64,
+}
+
+impl<F, U: FitnessCondition<F>> FitnessCondition<F> for Round<'_, U> {
+    fn step(&mut self) -> bool {
+        *self.steps += 1;
+        if self.stop.load(Ordering::Relaxed) {
+            false
+        } else if self.condition.step() {
+            true
+        } else {
+            self.stop.store(true, Ordering::Relaxed);
+            false
+        }
+    }
+
+    fn depth(&mut self, _: u32, _: F) -> bool {
+        // `select_parallel` itself checks whether to search the next depth.
+        true
+    }
+
+    fn node(&mut self, depth: u32, active: bool, fitness: F) {
+        self.condition.node(depth, active, fitness)
+    }
+}
@@ -14,6 +14,10 @@ impl<T: Game> Brute<T> {
         Self { player }
     }
 
+    /// Returns the best action when looking `depth` actions ahead, without any pruning.
+    ///
+    /// This searches the same states as `alpha_beta::Bot::select` using `Depth(depth)`,
+    /// so `u32::MAX` searches the complete game tree.
     pub fn select(&mut self, state: &T, depth: u32) -> Option<T::Action> {
         let (active, actions) = state.actions(self.player);
         if !active {
@@ -38,6 +42,8 @@ impl<T: Game> Brute<T> {
         Some(best.0)
     }
 
+    /// Returns `true` if `best` is at least as good as all other actions when looking `depth` actions ahead,
+    /// or if `best` is `None` and the bot is either not active or has no possible actions.
     pub fn check_if_best(&mut self, state: &T, best: Option<&T::Action>, depth: u32) -> bool {
         let (active, actions) = state.actions(self.player);
         if !active {
@@ -109,17 +115,28 @@ impl<T: Game> Brute<T> {
         actions
     }
 
-    fn minimax(&mut self, state: &T, action: &T::Action, depth: u32) -> T::Fitness {
+    /// Returns the fitness of `action` when looking `depth` actions further ahead.
+    pub fn minimax(&mut self, state: &T, action: &T::Action, depth: u32) -> T::Fitness {
         if depth == 0 {
-            state.look_ahead(&action, self.player)
+            state.look_ahead(action, self.player)
         } else {
             let mut state = state.clone();
-            let fitness = state.execute(&action, self.player);
+            let fitness = state.execute(action, self.player);
+            if state.is_terminal() || state.move_limit_reached() {
+                return fitness;
+            } else if let Some(fitness) = state.terminal_value(self.player) {
+                return fitness;
+            }
+
             let (active, actions) = state.actions(self.player);
+            let mut actions = actions.into_iter().peekable();
+            if depth == 1 && actions.peek().is_some() {
+                if let Some(fitness) = state.static_eval(self.player) {
+                    return fitness;
+                }
+            }
 
-            let iter = actions
-                .into_iter()
-                .map(|action| self.minimax(&state, &action, depth - 1));
+            let iter = actions.map(|action| self.minimax(&state, &action, depth - 1));
 
             if active { iter.max() } else { iter.min() }.unwrap_or(fitness)
         }
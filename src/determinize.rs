@@ -0,0 +1,120 @@
+//! Uses the search for games with hidden information, by searching multiple possible states of the game.
+//!
+//! This is called [determinization][det]: each possible state, or world, is treated as a game
+//! with perfect information and the results are combined afterwards.
+//!
+//! [det]: https://en.wikipedia.org/wiki/Determinization
+use crate::{Bot, Game, IntoFitnessCondition};
+
+/// How the fitnesses of an action in each world are combined, see [`select_over_worlds`][sel].
+///
+/// [sel]: fn.select_over_worlds.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Combine {
+    /// Uses the weighted average of the fitness in each world.
+    Average,
+    /// Uses the lowest fitness in any world, ignoring the weights.
+    WorstCase,
+}
+
+/// Selects the action which is best over all `worlds`, where `weights` is how likely each world is.
+///
+/// Each world is evaluated using [`Bot::evaluate_actions`][eval] with a clone of `condition`, so
+/// this takes a lot longer than a single call to [`Bot::select`][sel]. Only actions which are possible
+/// in all worlds are considered, preferring the first possible action of the first world in case of a tie.
+///
+/// Returns `None` if there are no worlds, the bot is not the active player or there is no action which
+/// is possible in every world.
+///
+/// # Panics
+///
+/// Panics if `weights` and `worlds` have a different length.
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::{Bot, ToCompletion, tree::Node};
+/// use rubot::determinize::{self, Combine};
+///
+/// // The first action is great in most worlds, but terrible in the last one.
+/// let worlds = [
+///     Node::root().with_children(&[Node::new(false, 10), Node::new(false, 2)]),
+///     Node::root().with_children(&[Node::new(false, 10), Node::new(false, 2)]),
+///     Node::root().with_children(&[Node::new(false, -10), Node::new(false, 2)]),
+/// ];
+///
+/// let mut bot = Bot::new(true);
+/// let weights = [1.0, 1.0, 1.0];
+/// let average = determinize::select_over_worlds(&mut bot, &worlds, &weights, Combine::Average, ToCompletion);
+/// assert_eq!(average, Some(0));
+/// let worst = determinize::select_over_worlds(&mut bot, &worlds, &weights, Combine::WorstCase, ToCompletion);
+/// assert_eq!(worst, Some(1));
+/// ```
+/// [eval]: ../alpha_beta/struct.Bot.html#method.evaluate_actions
+/// [sel]: ../alpha_beta/struct.Bot.html#method.select
+pub fn select_over_worlds<T, U>(
+    bot: &mut Bot<T>,
+    worlds: &[T],
+    weights: &[f64],
+    combine: Combine,
+    condition: U,
+) -> Option<T::Action>
+where
+    T: Game,
+    T::Fitness: Into<f64>,
+    U: IntoFitnessCondition<T::Fitness> + Clone,
+{
+    assert_eq!(
+        worlds.len(),
+        weights.len(),
+        "each world requires exactly one weight"
+    );
+
+    let (active, actions) = worlds.first()?.actions(bot.player());
+    if !active {
+        return None;
+    }
+
+    let evaluated: Vec<_> = worlds
+        .iter()
+        .map(|world| bot.evaluate_actions(world, condition.clone()))
+        .collect();
+    let total: f64 = weights.iter().sum();
+
+    let mut best: Option<(T::Action, f64)> = None;
+    for action in actions {
+        let fitnesses: Option<Vec<_>> = evaluated
+            .iter()
+            .map(|actions| {
+                actions
+                    .iter()
+                    .find(|(a, _)| *a == action)
+                    .map(|&(_, fitness)| fitness)
+            })
+            .collect();
+        let fitnesses = match fitnesses {
+            Some(fitnesses) => fitnesses,
+            None => continue,
+        };
+
+        let score = match combine {
+            Combine::Average => {
+                fitnesses
+                    .into_iter()
+                    .zip(weights)
+                    .map(|(fitness, weight)| fitness.into() * weight)
+                    .sum::<f64>()
+                    / total
+            }
+            Combine::WorstCase => fitnesses
+                .into_iter()
+                .map(Into::into)
+                .fold(f64::INFINITY, f64::min),
+        };
+        if best.as_ref().map_or(true, |&(_, best)| score > best) {
+            best = Some((action, score));
+        }
+    }
+
+    best.map(|(action, _)| action)
+}
@@ -0,0 +1,110 @@
+//! A fixed-point number which can be used as a [`Game::Fitness`][fit].
+//!
+//! [fit]: ../trait.Game.html#associatedtype.Fitness
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A fixed-point number with two decimal places.
+///
+/// Unlike floats, `Fixed` implements `Ord` and is always deterministic, so it can be used as the
+/// [`Fitness`][fit] of a game without having to scale all values by hand.
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::Fixed;
+///
+/// let pawn = Fixed::from(1);
+/// let bishop = Fixed::from_hundredths(325);
+///
+/// assert!(bishop > pawn * 3);
+/// assert_eq!((bishop - pawn).to_string(), "2.25");
+/// assert_eq!((-pawn).to_string(), "-1.00");
+/// assert_eq!(-Fixed::MIN, Fixed::MAX);
+/// ```
+/// [fit]: ../trait.Game.html#associatedtype.Fitness
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed(i64);
+
+impl Fixed {
+    /// The largest possible value, which can be used as a sentinel for a won game.
+    pub const MAX: Fixed = Fixed(i64::MAX);
+    /// The smallest value which can still be negated, which can be used as a sentinel for a lost game.
+    ///
+    /// This is `-Fixed::MAX`, so `-Fixed::MIN` does not overflow.
+    pub const MIN: Fixed = Fixed(-i64::MAX);
+
+    /// Creates a new `Fixed` from a multiple of `0.01`, so `Fixed::from_hundredths(150)` is `1.50`.
+    pub const fn from_hundredths(hundredths: i64) -> Self {
+        Fixed(hundredths)
+    }
+
+    /// Returns `self` as a multiple of `0.01`.
+    pub const fn hundredths(self) -> i64 {
+        self.0
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(value: i32) -> Self {
+        Fixed(i64::from(value) * 100)
+    }
+}
+
+impl From<Fixed> for f64 {
+    fn from(value: Fixed) -> Self {
+        value.0 as f64 / 100.0
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let abs = self.0.unsigned_abs();
+        write!(f, "{}{}.{:02}", sign, abs / 100, abs % 100)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+
+    fn add(self, other: Fixed) -> Fixed {
+        Fixed(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, other: Fixed) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, other: Fixed) -> Fixed {
+        Fixed(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, other: Fixed) {
+        self.0 -= other.0;
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Fixed {
+        Fixed(-self.0)
+    }
+}
+
+impl Mul<i64> for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, factor: i64) -> Fixed {
+        Fixed(self.0 * factor)
+    }
+}
@@ -0,0 +1,130 @@
+use super::{Fitness, Player};
+use crate::Game;
+
+const COLUMNS: usize = 7;
+const ROWS: usize = 6;
+
+/// [Connect Four][c4] on the standard board with 7 columns and 6 rows.
+///
+/// The columns are numbered from `0` to `6`. As this game is too large to be searched completely,
+/// the fitness is only `Fitness::Even` until the game is over.
+///
+/// [c4]: https://en.wikipedia.org/wiki/Connect_Four
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConnectFour {
+    /// The pieces of each column, from bottom to top.
+    columns: [[Option<Player>; ROWS]; COLUMNS],
+    heights: [usize; COLUMNS],
+    active: Player,
+    winner: Option<Player>,
+}
+
+impl Default for ConnectFour {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConnectFour {
+    /// Creates an empty board, where `Player::First` moves first.
+    pub fn new() -> Self {
+        ConnectFour {
+            columns: [[None; ROWS]; COLUMNS],
+            heights: [0; COLUMNS],
+            active: Player::First,
+            winner: None,
+        }
+    }
+
+    /// Returns the piece in `column` at `row`, counting from the bottom.
+    pub fn piece(&self, column: usize, row: usize) -> Option<Player> {
+        self.columns[column][row]
+    }
+
+    /// The player who has to move next.
+    pub fn active(&self) -> Player {
+        self.active
+    }
+
+    /// Returns the winner, if there is one.
+    pub fn winner(&self) -> Option<Player> {
+        self.winner
+    }
+
+    /// Returns `true` if the game has either been won or the board is full.
+    pub fn is_finished(&self) -> bool {
+        self.winner.is_some() || self.heights.iter().all(|&h| h == ROWS)
+    }
+
+    /// Drops a piece of the active player into `column`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the game is already finished or `column` is full.
+    pub fn drop_piece(&mut self, column: usize) {
+        assert!(!self.is_finished(), "the game is already finished");
+        let row = self.heights[column];
+        assert!(row < ROWS, "column {} is full", column);
+        self.columns[column][row] = Some(self.active);
+        self.heights[column] += 1;
+
+        let count = |dc: isize, dr: isize| {
+            (1..4)
+                .take_while(|&i| {
+                    let c = column as isize + dc * i;
+                    let r = row as isize + dr * i;
+                    c >= 0
+                        && r >= 0
+                        && (c as usize) < COLUMNS
+                        && (r as usize) < ROWS
+                        && self.columns[c as usize][r as usize] == Some(self.active)
+                })
+                .count()
+        };
+        if [(1, 0), (0, 1), (1, 1), (1, -1)]
+            .iter()
+            .any(|&(dc, dr)| 1 + count(dc, dr) + count(-dc, -dr) >= 4)
+        {
+            self.winner = Some(self.active);
+        }
+        self.active = self.active.other();
+    }
+}
+
+impl Game for ConnectFour {
+    type Player = Player;
+    /// The column to drop a piece into.
+    type Action = usize;
+    type Fitness = Fitness;
+    type Actions = Vec<usize>;
+
+    fn actions(&self, player: Player) -> (bool, Self::Actions) {
+        let actions = if self.is_finished() {
+            Vec::new()
+        } else {
+            (0..COLUMNS).filter(|&c| self.heights[c] < ROWS).collect()
+        };
+        (player == self.active, actions)
+    }
+
+    fn execute(&mut self, action: &usize, player: Player) -> Fitness {
+        self.drop_piece(*action);
+        Fitness::of(self.winner, player)
+    }
+
+    fn is_upper_bound(&self, fitness: Fitness, _: Player) -> bool {
+        fitness == Fitness::Win
+    }
+
+    fn is_lower_bound(&self, fitness: Fitness, _: Player) -> bool {
+        fitness == Fitness::Loss
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.is_finished()
+    }
+
+    fn winner(&self) -> Option<Player> {
+        self.winner
+    }
+}
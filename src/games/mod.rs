@@ -0,0 +1,62 @@
+//! Small reference implementations of [`Game`][game], which are only available with the `games` feature.
+//!
+//! These games are mostly meant as examples of how to implement `Game` and for testing,
+//! they are not optimized in any way.
+//!
+//! ```rust
+//! use rubot::{Bot, ToCompletion, games::{Nim, Player}};
+//!
+//! // With 21 flags, the first player can always win by leaving a multiple of 4.
+//! let mut bot = Bot::new(Player::First);
+//! assert_eq!(bot.select(&Nim::new(21, 3), ToCompletion), Some(1));
+//! ```
+//!
+//! [game]: ../trait.Game.html
+mod connect_four;
+mod nim;
+mod tic_tac_toe;
+
+pub use connect_four::ConnectFour;
+pub use nim::Nim;
+pub use tic_tac_toe::TicTacToe;
+
+/// One of the two players of the games in this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Player {
+    /// The player who moves first.
+    First,
+    /// The player who moves second.
+    Second,
+}
+
+impl Player {
+    /// Returns the opponent of `self`.
+    pub fn other(self) -> Player {
+        match self {
+            Player::First => Player::Second,
+            Player::Second => Player::First,
+        }
+    }
+}
+
+/// The fitness used by all games in this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Fitness {
+    /// The opponent has won.
+    Loss,
+    /// The game is either still ongoing or a draw.
+    Even,
+    /// The player has won.
+    Win,
+}
+
+impl Fitness {
+    /// Returns the fitness for `player` given the winner of the game, if there is one.
+    fn of(winner: Option<Player>, player: Player) -> Fitness {
+        match winner {
+            Some(winner) if winner == player => Fitness::Win,
+            Some(_) => Fitness::Loss,
+            None => Fitness::Even,
+        }
+    }
+}
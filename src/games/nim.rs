@@ -0,0 +1,89 @@
+use super::{Fitness, Player};
+use crate::Game;
+
+use std::ops::RangeInclusive;
+
+/// A simple version of [Nim][nim] with a single heap, where the player removing the last flag wins.
+///
+/// Each turn, the active player removes between `1` and `max_take` flags.
+///
+/// [nim]: https://en.wikipedia.org/wiki/Nim
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Nim {
+    flags: u32,
+    max_take: u32,
+    active: Player,
+}
+
+impl Nim {
+    /// Creates a new game with `flags` flags, where `Player::First` moves first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_take` is `0`.
+    pub fn new(flags: u32, max_take: u32) -> Self {
+        assert_ne!(
+            max_take, 0,
+            "players must be able to remove at least one flag"
+        );
+        Nim {
+            flags,
+            max_take,
+            active: Player::First,
+        }
+    }
+
+    /// The amount of remaining flags.
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// The player who has to move next.
+    pub fn active(&self) -> Player {
+        self.active
+    }
+
+    /// Returns the winner once all flags were removed.
+    pub fn winner(&self) -> Option<Player> {
+        if self.flags == 0 {
+            Some(self.active.other())
+        } else {
+            None
+        }
+    }
+}
+
+impl Game for Nim {
+    type Player = Player;
+    /// The amount of removed flags.
+    type Action = u32;
+    type Fitness = Fitness;
+    type Actions = RangeInclusive<u32>;
+
+    fn actions(&self, player: Player) -> (bool, Self::Actions) {
+        // This is empty once all flags are removed.
+        (player == self.active, 1..=self.max_take.min(self.flags))
+    }
+
+    fn execute(&mut self, action: &u32, player: Player) -> Fitness {
+        self.flags -= action;
+        self.active = self.active.other();
+        Fitness::of(self.winner(), player)
+    }
+
+    fn is_upper_bound(&self, fitness: Fitness, _: Player) -> bool {
+        fitness == Fitness::Win
+    }
+
+    fn is_lower_bound(&self, fitness: Fitness, _: Player) -> bool {
+        fitness == Fitness::Loss
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.flags == 0
+    }
+
+    fn winner(&self) -> Option<Player> {
+        Nim::winner(self)
+    }
+}
@@ -0,0 +1,121 @@
+use super::{Fitness, Player};
+use crate::Game;
+
+/// All rows, columns and diagonals of the board.
+const LINES: [[usize; 3]; 8] = [
+    [0, 1, 2],
+    [3, 4, 5],
+    [6, 7, 8],
+    [0, 3, 6],
+    [1, 4, 7],
+    [2, 5, 8],
+    [0, 4, 8],
+    [2, 4, 6],
+];
+
+/// [Tic-tac-toe][ttt] on a 3x3 board.
+///
+/// The tiles are indexed row by row, so the action `4` places a piece in the center.
+///
+/// [ttt]: https://en.wikipedia.org/wiki/Tic-tac-toe
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TicTacToe {
+    tiles: [Option<Player>; 9],
+    active: Player,
+    winner: Option<Player>,
+}
+
+impl Default for TicTacToe {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TicTacToe {
+    /// Creates an empty board, where `Player::First` moves first.
+    pub fn new() -> Self {
+        TicTacToe {
+            tiles: [None; 9],
+            active: Player::First,
+            winner: None,
+        }
+    }
+
+    /// The tiles of the board, row by row.
+    pub fn tiles(&self) -> &[Option<Player>; 9] {
+        &self.tiles
+    }
+
+    /// The player who has to move next.
+    pub fn active(&self) -> Player {
+        self.active
+    }
+
+    /// Returns the winner, if there is one.
+    pub fn winner(&self) -> Option<Player> {
+        self.winner
+    }
+
+    /// Returns `true` if the game has either been won or no tile is empty.
+    pub fn is_finished(&self) -> bool {
+        self.winner.is_some() || self.tiles.iter().all(Option::is_some)
+    }
+
+    /// Places a piece of the active player on `tile`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the game is already finished or `tile` is not empty.
+    pub fn place(&mut self, tile: usize) {
+        assert!(!self.is_finished(), "the game is already finished");
+        assert!(self.tiles[tile].is_none(), "tile {} is not empty", tile);
+        self.tiles[tile] = Some(self.active);
+        let tiles = &self.tiles;
+        if LINES
+            .iter()
+            .filter(|line| line.contains(&tile))
+            .any(|line| line.iter().all(|&t| tiles[t] == Some(self.active)))
+        {
+            self.winner = Some(self.active);
+        }
+        self.active = self.active.other();
+    }
+}
+
+impl Game for TicTacToe {
+    type Player = Player;
+    /// The index of an empty tile.
+    type Action = usize;
+    type Fitness = Fitness;
+    type Actions = Vec<usize>;
+
+    fn actions(&self, player: Player) -> (bool, Self::Actions) {
+        let actions = if self.is_finished() {
+            Vec::new()
+        } else {
+            (0..9).filter(|&t| self.tiles[t].is_none()).collect()
+        };
+        (player == self.active, actions)
+    }
+
+    fn execute(&mut self, action: &usize, player: Player) -> Fitness {
+        self.place(*action);
+        Fitness::of(self.winner, player)
+    }
+
+    fn is_upper_bound(&self, fitness: Fitness, _: Player) -> bool {
+        fitness == Fitness::Win
+    }
+
+    fn is_lower_bound(&self, fitness: Fitness, _: Player) -> bool {
+        fitness == Fitness::Loss
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.is_finished()
+    }
+
+    fn winner(&self) -> Option<Player> {
+        self.winner
+    }
+}
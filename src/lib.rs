@@ -49,6 +49,13 @@
 //! [ex]:https://github.com/lcnr/rubot/tree/master/examples
 //! [game]:trait.Game.html
 pub mod alpha_beta;
+pub mod determinize;
+pub mod fixed;
+#[cfg(feature = "games")]
+pub mod games;
+pub mod path;
+pub mod play;
+pub mod quantize;
 pub mod tree;
 
 #[allow(unused)]
@@ -60,6 +67,8 @@ mod tests;
 use std::cmp::PartialEq;
 use std::fmt::{self, Debug};
 use std::ops::Drop;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// An interface required to interact with [`GameBot`s][bot].
@@ -195,13 +204,80 @@ pub trait Game: Clone {
     /// An executable action.
     type Action: PartialEq + Clone + Send;
     /// The fitness of a state.
+    ///
+    /// The bot always tries to maximize the fitness according to its `Ord` implementation.
+    /// To interpret the same value differently, wrap it in a type with the desired ordering.
+    /// A fitness which should be minimized can use [`std::cmp::Reverse`][rev], while
+    /// tuples are compared lexicographically. A tuple like `(material, mobility)` therefore uses its second
+    /// element to choose between states with the same first element at every depth of the search, not only
+    /// for the root actions like [`Bot::with_tie_break`][tb].
+    ///
+    /// The search only compares fitnesses and never negates them, so the extreme values of a type,
+    /// like `i32::MIN` and `i32::MAX`, can safely be used for lost and won games. The only exceptions
+    /// are the methods which require arithmetic on fitnesses:
+    ///
+    /// - [`Bot::best_margin`][margin] subtracts the fitnesses of the two best actions.
+    /// - [`Bot::with_draw_value`][draw] and [`Bot::with_contempt`][contempt] compute the bounds
+    ///   `value - epsilon` and `value + epsilon` of the draws and their replacement `value - margin`.
+    /// - [`Bot::with_fitness_blur`][blur] subtracts its margin from the best fitness, unless it is
+    ///   an upper or lower bound.
+    /// - [`determinize::select_over_worlds`][worlds] converts fitnesses to `f64` to average them.
+    ///
+    /// ```rust
+    /// use std::cmp::Reverse;
+    /// use std::ops::RangeInclusive;
+    ///
+    /// /// Choose the smallest number, the game ends once a number was chosen.
+    /// #[derive(Clone)]
+    /// struct ChooseSmall {
+    ///     chosen: bool,
+    /// }
+    ///
+    /// impl rubot::Game for ChooseSmall {
+    ///     type Player = ();
+    ///     type Action = u8;
+    ///     type Fitness = Reverse<u8>;
+    ///     type Actions = RangeInclusive<u8>;
+    ///
+    ///     fn actions(&self, _: Self::Player) -> (bool, Self::Actions) {
+    ///         if self.chosen {
+    ///             (true, 1..=0)
+    ///         } else {
+    ///             (true, 1..=10)
+    ///         }
+    ///     }
+    ///
+    ///     fn execute(&mut self, action: &u8, _: Self::Player) -> Self::Fitness {
+    ///         self.chosen = true;
+    ///         Reverse(*action)
+    ///     }
+    /// }
+    ///
+    /// let mut bot = rubot::Bot::new(());
+    /// let game = ChooseSmall { chosen: false };
+    /// assert_eq!(bot.select(&game, rubot::ToCompletion), Some(1));
+    /// ```
+    /// [rev]: https://doc.rust-lang.org/std/cmp/struct.Reverse.html
+    /// [margin]: alpha_beta/struct.Bot.html#method.best_margin
+    /// [draw]: alpha_beta/struct.Bot.html#method.with_draw_value
+    /// [contempt]: alpha_beta/struct.Bot.html#method.with_contempt
+    /// [blur]: alpha_beta/struct.Bot.html#method.with_fitness_blur
+    /// [worlds]: determinize/fn.select_over_worlds.html
+    /// [tb]: alpha_beta/struct.Bot.html#method.with_tie_break
     type Fitness: Ord + Copy + Send;
     /// The collection returned by [`actions`][ac].
     ///
+    /// This can be a lazy iterator, but the bot always consumes it completely
+    /// before searching the given state, as the actions are sorted by their fitness.
+    ///
     /// [ac]:trait.Game.html#tymethod.actions
     type Actions: IntoIterator<Item = Self::Action>;
 
     /// Returns all currently possible actions and if they are executed by the given `player`.
+    ///
+    /// Players do not have to alternate, the bot only relies on this function to decide who is active.
+    /// A player taking an extra turn, for example after a capture, simply stays active after
+    /// executing their action.
     fn actions(&self, player: Self::Player) -> (bool, Self::Actions);
 
     /// Execute a given `action`, returning the new `fitness` for the given `player`.
@@ -271,6 +347,110 @@ pub trait Game: Clone {
         let _ = (fitness, player);
         false
     }
+
+    /// Returns `true` if the game is over, for example because of a checkmate.
+    ///
+    /// The bot does not look at the possible actions of a terminal state, so this allows
+    /// ending the game without having to generate all actions first. Just like a state without any possible actions,
+    /// the fitness returned by [`execute`][exe] for the action leading to a terminal state is used as its final fitness.
+    /// Iterative deepening reaches terminal states on shorter paths first, so the bot prefers the fastest win
+    /// in case [`is_upper_bound`][upper] holds for its fitness. The default implementation returns `false`.
+    ///
+    /// [exe]: trait.Game.html#tymethod.execute
+    /// [upper]: trait.Game.html#method.is_upper_bound
+    #[inline]
+    fn is_terminal(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the game ended in a draw because of a rule like the [fifty-move rule][fifty],
+    /// even though there are still possible actions.
+    ///
+    /// Such a state is treated like a state without any possible actions, so the bot uses the fitness
+    /// returned by [`execute`][exe] for the action leading to it. This should therefore be the fitness of a draw.
+    /// The default implementation returns `false`.
+    ///
+    /// [fifty]: https://en.wikipedia.org/wiki/Fifty-move_rule
+    /// [exe]: trait.Game.html#tymethod.execute
+    #[inline]
+    fn move_limit_reached(&self) -> bool {
+        false
+    }
+
+    /// Returns the fitness of a state without any possible actions for the given `player`.
+    ///
+    /// This is only used by [`Bot::evaluate`][eval], as the fitness of a state is otherwise
+    /// always returned by [`execute`][exe]. The default implementation returns `None`.
+    ///
+    /// [eval]: alpha_beta/struct.Bot.html#method.evaluate
+    /// [exe]: trait.Game.html#tymethod.execute
+    #[inline]
+    fn terminal_fitness(&self, player: Self::Player) -> Option<Self::Fitness> {
+        let _ = player;
+        None
+    }
+
+    /// Returns the known fitness of this state for the given `player`, for example because
+    /// the position is clearly lost and the game would be resigned.
+    ///
+    /// In case this returns `Some`, the bot does not look at the possible actions of the state and instead uses
+    /// the returned fitness, just like for a [terminal][term] state with this fitness. This can
+    /// save a lot of time in positions where further search does not change anything.
+    /// The state passed to [`Bot::select`][sel] itself is still searched.
+    /// States for which [`is_terminal`][term] or [`move_limit_reached`][limit] return `true` ignore this.
+    /// The default implementation returns `None`.
+    ///
+    /// [term]: trait.Game.html#method.is_terminal
+    /// [limit]: trait.Game.html#method.move_limit_reached
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    #[inline]
+    fn terminal_value(&self, player: Self::Player) -> Option<Self::Fitness> {
+        let _ = player;
+        None
+    }
+
+    /// Returns the estimated fitness of this state for the given `player`, used in case
+    /// the bot stops searching at this state because it reached its maximum depth.
+    ///
+    /// Without a static evaluation, the bot instead looks at the fitness of all possible actions of such a state using
+    /// [`look_ahead`][la], so the fitness returned by [`execute`][exe] is both used as a heuristic and to score finished games.
+    /// Implementing this allows a separate heuristic, while `execute` is only used for states without
+    /// any possible actions and [terminal][term] states. As the actions of the state itself are not looked at,
+    /// the bot then looks one action less ahead at each depth. The default implementation returns `None`.
+    ///
+    /// [la]: trait.Game.html#method.look_ahead
+    /// [exe]: trait.Game.html#tymethod.execute
+    /// [term]: trait.Game.html#method.is_terminal
+    #[inline]
+    fn static_eval(&self, player: Self::Player) -> Option<Self::Fitness> {
+        let _ = player;
+        None
+    }
+
+    /// Returns an action which is probably the best one in this state, for example from
+    /// a pattern database or a cheap policy.
+    ///
+    /// The bot searches this action first, before ordering the remaining ones by their fitness.
+    /// This only changes the order in which actions are searched, a bad hint only makes the
+    /// search slower. Hints which are not one of the [`actions`][actions] are ignored.
+    /// The default implementation returns `None`.
+    ///
+    /// [actions]: trait.Game.html#tymethod.actions
+    #[inline]
+    fn hint_action(&self, player: Self::Player) -> Option<Self::Action> {
+        let _ = player;
+        None
+    }
+
+    /// Returns the winner of a finished game, or `None` if the game is still ongoing or ended in a draw.
+    ///
+    /// This is not used while searching, only by [`play::Outcome::of`][outcome]. The default implementation returns `None`.
+    ///
+    /// [outcome]: play/enum.Outcome.html#method.of
+    #[inline]
+    fn winner(&self) -> Option<Self::Player> {
+        None
+    }
 }
 
 /// Converts a type into a [`RunCondition`][rc] used by [`Bot::select`][sel].
@@ -304,6 +484,10 @@ pub trait Game: Clone {
 /// - [`Depth`][depth]: `select` analyses up the to given depth and returns to best action at that depth
 /// - [`Instant`][instant]: `select` runs until the given `Instant` is in the past
 /// - [`Logger`][logger]: takes another run condition and stores information about the last call to `select`
+/// - [`MinDepth`][min]: takes another run condition and ignores it until the given depth is reached
+/// - [`Cancellable`][cancel]: takes another run condition and also stops once a flag is set
+/// - [`SkipUnfinishable`][skip]: like `Duration`, but does not start depths which probably can not be completed
+/// - [`Arc`][arc]`<impl SharedCondition>`: a [`SharedCondition`][shared] used by multiple searches at the same time
 ///
 /// [rc]: trait.RunCondition.html
 /// [dur]: https://doc.rust-lang.org/std/time/struct.Duration.html
@@ -311,6 +495,11 @@ pub trait Game: Clone {
 /// [depth]: struct.Depth.html
 /// [instant]: https://doc.rust-lang.org/std/time/struct.Instant.html
 /// [logger]: struct.Logger.html
+/// [min]: struct.MinDepth.html
+/// [skip]: struct.SkipUnfinishable.html
+/// [cancel]: struct.Cancellable.html
+/// [arc]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+/// [shared]: trait.SharedCondition.html
 /// [sel]: alpha_beta/struct.Bot.html#method.select
 ///
 pub trait IntoRunCondition {
@@ -381,6 +570,97 @@ impl IntoRunCondition for Duration {
     }
 }
 
+/// Can be converted into a [`RunCondition`][rc] which runs for at most `self.0`, similar to [`Duration`][dur],
+/// but does not start a new depth in case it probably can not be completed in the remaining time.
+///
+/// The time needed for the next depth is estimated from the time the last depth took and
+/// how much longer it took than the one before. As incomplete depths are mostly wasted,
+/// this usually allows the remaining time to be used for something else.
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::{Bot, SkipUnfinishable, ToCompletion, tree::Node};
+/// use std::time::Duration;
+///
+/// # #[rustfmt::skip]
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 7).with_children(&[
+///         Node::new(true, 4),
+///         Node::new(true, 2),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 8),
+///         Node::new(true, 9)
+///     ]),
+/// ]);
+///
+/// let mut bot = Bot::new(true);
+/// let selected = bot.select(&tree, SkipUnfinishable(Duration::from_secs(1)));
+/// assert_eq!(selected, bot.select(&tree, ToCompletion));
+/// ```
+/// [rc]: trait.RunCondition.html
+/// [dur]: https://doc.rust-lang.org/std/time/struct.Duration.html
+#[derive(Clone, Copy, Debug)]
+pub struct SkipUnfinishable(pub Duration);
+
+/// The [`RunCondition`][rc] created by [`SkipUnfinishable`][skip]`::into_run_condition`
+///
+/// [rc]: trait.RunCondition.html
+/// [skip]: struct.SkipUnfinishable.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InnerSkipUnfinishable {
+    end: Instant,
+    depth_start: Instant,
+    last: Option<Duration>,
+    previous: Option<Duration>,
+}
+
+impl IntoRunCondition for SkipUnfinishable {
+    type RunCondition = InnerSkipUnfinishable;
+
+    fn into_run_condition(self) -> InnerSkipUnfinishable {
+        let now = Instant::now();
+        InnerSkipUnfinishable {
+            end: now + self.0,
+            depth_start: now,
+            last: None,
+            previous: None,
+        }
+    }
+}
+
+impl InnerSkipUnfinishable {
+    /// The estimated duration of the next depth.
+    fn estimate(&self) -> Duration {
+        match (self.previous, self.last) {
+            (Some(previous), Some(last)) if previous > Duration::from_secs(0) => {
+                last.mul_f64(last.as_secs_f64() / previous.as_secs_f64())
+            }
+            (_, Some(last)) => last,
+            (_, None) => Duration::from_secs(0),
+        }
+    }
+}
+
+impl RunCondition for InnerSkipUnfinishable {
+    #[inline]
+    fn step(&mut self) -> bool {
+        Instant::now() < self.end
+    }
+
+    fn depth(&mut self, depth: u32) -> bool {
+        let now = Instant::now();
+        if depth > 0 {
+            self.previous = self.last;
+            self.last = Some(now - self.depth_start);
+        }
+        self.depth_start = now;
+        now + self.estimate() < self.end
+    }
+}
+
 /// A condition which indicates if [`Bot::select`][sel] should keep on running.
 /// It is recommended to use [`Duration`][dur] for nearly all use cases.
 ///
@@ -394,6 +674,512 @@ pub trait RunCondition {
     fn step(&mut self) -> bool;
     /// Called after every finished search depth, instantly stops all calculations by returning `false`.
     fn depth(&mut self, depth: u32) -> bool;
+
+    /// Called in case the search was stopped by a condition wrapping `self`,
+    /// for example [`StopWhen`][sw], even though `self` would have continued.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// [sw]: struct.StopWhen.html
+    #[inline]
+    fn stopped(&mut self) {}
+}
+
+/// A generalization of [`RunCondition`][rc] which is also told the fitness of the currently best action
+/// before every search depth.
+///
+/// This trait is implemented for every `RunCondition`, ignoring the given fitness.
+/// It allows for conditions like "stop once the game is decisively won" without
+/// requiring the bot to know about them. The easiest way to create such a condition is [`StopWhen`][sw].
+///
+/// [rc]: trait.RunCondition.html
+/// [sw]: struct.StopWhen.html
+pub trait FitnessCondition<F> {
+    /// Called at each search step, instantly stops all calculations by returning `false`.
+    fn step(&mut self) -> bool;
+    /// Called before searching `depth` with the fitness of the currently best action,
+    /// instantly stops all calculations by returning `false`.
+    ///
+    /// For `depth > 0`, `fitness` is the result of the finished depth `depth - 1`.
+    /// As nothing has been searched yet when this is called with `depth == 0`, `fitness` is
+    /// then the highest fitness returned by [`Game::look_ahead`][la] for the possible actions.
+    ///
+    /// [la]: trait.Game.html#method.look_ahead
+    fn depth(&mut self, depth: u32, fitness: F) -> bool;
+
+    /// Called once the `fitness` of a searched state is known, where `depth` is the amount of
+    /// actions the bot still looks ahead from this state and `active` is whether the bot is the active player.
+    ///
+    /// This is called very often, see [`OnNode`][on] for a simple way to use it. In case of a cutoff,
+    /// `fitness` is only a bound. Terminal states and states without possible actions are not searched,
+    /// so this is not called for them. The default implementation does nothing.
+    ///
+    /// [on]: struct.OnNode.html
+    #[inline]
+    fn node(&mut self, depth: u32, active: bool, fitness: F) {
+        let _ = (depth, active, fitness);
+    }
+}
+
+impl<F, T: RunCondition> FitnessCondition<F> for T {
+    #[inline]
+    fn step(&mut self) -> bool {
+        RunCondition::step(self)
+    }
+
+    #[inline]
+    fn depth(&mut self, depth: u32, _: F) -> bool {
+        RunCondition::depth(self, depth)
+    }
+}
+
+/// Converts a type into a [`FitnessCondition`][fc] used by [`Bot::select`][sel].
+///
+/// This trait is implemented for every type implementing [`IntoRunCondition`][into],
+/// so it is only relevant when writing a custom `FitnessCondition`.
+///
+/// [fc]: trait.FitnessCondition.html
+/// [into]: trait.IntoRunCondition.html
+/// [sel]: alpha_beta/struct.Bot.html#method.select
+pub trait IntoFitnessCondition<F> {
+    type FitnessCondition: FitnessCondition<F>;
+
+    /// consumes `self` and returns a `FitnessCondition`.
+    fn into_fitness_condition(self) -> Self::FitnessCondition;
+}
+
+impl<F, T: IntoRunCondition> IntoFitnessCondition<F> for T {
+    type FitnessCondition = T::RunCondition;
+
+    fn into_fitness_condition(self) -> T::RunCondition {
+        self.into_run_condition()
+    }
+}
+
+/// Wraps a [`IntoRunCondition`][into] and additionally stops the search once `predicate`
+/// returns `true` for the next depth and the fitness of the currently best action,
+/// see [`FitnessCondition::depth`][depth] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, tree::Node, Logger, StopWhen, ToCompletion};
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 7).with_children(&[
+///         Node::new(true, 4),
+///         Node::new(true, 2),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 8),
+///         Node::new(true, 9)
+///     ]),
+/// ]);
+///
+/// let mut bot = Bot::new(true);
+/// let mut logger = Logger::new(ToCompletion);
+/// // A fitness of `7` is good enough for us, so we do not have to look any further.
+/// let condition = StopWhen::new(&mut logger, |_depth, fitness| fitness >= 7);
+/// assert_eq!(bot.select(&tree, condition), Some(0));
+/// assert_eq!(logger.depth(), 0);
+/// assert!(!logger.completed());
+/// ```
+/// [into]: trait.IntoRunCondition.html
+/// [depth]: trait.FitnessCondition.html#tymethod.depth
+#[derive(Clone, Copy, Debug)]
+pub struct StopWhen<T, P> {
+    condition: T,
+    predicate: P,
+}
+
+impl<T, P> StopWhen<T, P> {
+    /// Creates a new `StopWhen`, stopping once either `condition` or `predicate` says so.
+    pub fn new(condition: T, predicate: P) -> Self {
+        Self {
+            condition,
+            predicate,
+        }
+    }
+}
+
+/// The [`FitnessCondition`][fc] created by [`StopWhen`][sw]`::into_fitness_condition`
+///
+/// [fc]: trait.FitnessCondition.html
+/// [sw]: struct.StopWhen.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InnerStopWhen<T, P>(T, P);
+
+impl<F, T: IntoRunCondition, P: FnMut(u32, F) -> bool> IntoFitnessCondition<F> for StopWhen<T, P> {
+    type FitnessCondition = InnerStopWhen<T::RunCondition, P>;
+
+    fn into_fitness_condition(self) -> Self::FitnessCondition {
+        InnerStopWhen(self.condition.into_run_condition(), self.predicate)
+    }
+}
+
+impl<F, T: RunCondition, P: FnMut(u32, F) -> bool> FitnessCondition<F> for InnerStopWhen<T, P> {
+    #[inline]
+    fn step(&mut self) -> bool {
+        self.0.step()
+    }
+
+    #[inline]
+    fn depth(&mut self, depth: u32, fitness: F) -> bool {
+        if !self.0.depth(depth) {
+            false
+        } else if (self.1)(depth, fitness) {
+            self.0.stopped();
+            false
+        } else {
+            true
+        }
+    }
+}
+
+/// Wraps a [`IntoFitnessCondition`][into] and calls `callback` for every searched state,
+/// see [`FitnessCondition::node`][node] for the meaning of its arguments.
+///
+/// This allows watching the search while it is running, for example to visualize the searched tree.
+/// As this adds some work to every step of the search, it should only be used for debugging and teaching.
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::{Bot, OnNode, ToCompletion, tree::Node};
+///
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 7).with_children(&[
+///         Node::new(true, 4),
+///         Node::new(true, 2),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 8),
+///         Node::new(true, 9)
+///     ]),
+/// ]);
+///
+/// let mut nodes = Vec::new();
+/// let condition = OnNode::new(ToCompletion, |depth, active, fitness| {
+///     nodes.push((depth, active, fitness))
+/// });
+/// assert_eq!(Bot::new(true).select(&tree, condition), Some(1));
+/// // The opponent chooses `2` after the first and `8` after the second action.
+/// assert!(nodes.contains(&(0, false, 2)));
+/// assert!(nodes.contains(&(0, false, 8)));
+/// ```
+/// [into]: trait.IntoFitnessCondition.html
+/// [node]: trait.FitnessCondition.html#method.node
+#[derive(Clone, Copy, Debug)]
+pub struct OnNode<T, C> {
+    condition: T,
+    callback: C,
+}
+
+impl<T, C> OnNode<T, C> {
+    /// Creates a new `OnNode`, which calls `callback` for every searched state and otherwise behaves like `condition`.
+    pub fn new(condition: T, callback: C) -> Self {
+        Self {
+            condition,
+            callback,
+        }
+    }
+}
+
+/// The [`FitnessCondition`][fc] created by [`OnNode`][on]`::into_fitness_condition`
+///
+/// [fc]: trait.FitnessCondition.html
+/// [on]: struct.OnNode.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InnerOnNode<T, C>(T, C);
+
+impl<F: Copy, T: IntoFitnessCondition<F>, C: FnMut(u32, bool, F)> IntoFitnessCondition<F>
+    for OnNode<T, C>
+{
+    type FitnessCondition = InnerOnNode<T::FitnessCondition, C>;
+
+    fn into_fitness_condition(self) -> Self::FitnessCondition {
+        InnerOnNode(self.condition.into_fitness_condition(), self.callback)
+    }
+}
+
+impl<F: Copy, T: FitnessCondition<F>, C: FnMut(u32, bool, F)> FitnessCondition<F>
+    for InnerOnNode<T, C>
+{
+    #[inline]
+    fn step(&mut self) -> bool {
+        self.0.step()
+    }
+
+    #[inline]
+    fn depth(&mut self, depth: u32, fitness: F) -> bool {
+        self.0.depth(depth, fitness)
+    }
+
+    #[inline]
+    fn node(&mut self, depth: u32, active: bool, fitness: F) {
+        (self.1)(depth, active, fitness);
+        self.0.node(depth, active, fitness);
+    }
+}
+
+/// Wraps a [`IntoRunCondition`][into] and ignores it until the given depth is reached,
+/// guaranteeing that at least the depths searched by [`Depth`][depth]`(depth)` are completed.
+///
+/// The wrapped condition is still called during these depths, so a wrapped [`Logger`][logger]
+/// records them, but returning `false` only stops the search once `depth` is reached.
+/// In case the game ends before `depth`, the search terminates as usual.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, tree::Node, Logger, MinDepth};
+/// # use std::time::Duration;
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 7).with_children(&[
+///         Node::new(true, 4),
+///         Node::new(true, 2),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 8),
+///         Node::new(true, 9)
+///     ]),
+/// ]);
+///
+/// let mut bot = Bot::new(true);
+/// // Even without any time, this returns the same action as `Depth(1)`.
+/// let mut logger = Logger::new(MinDepth::new(1, Duration::from_secs(0)));
+/// assert_eq!(bot.select(&tree, &mut logger), Some(1));
+/// assert_eq!(logger.depth(), 1);
+/// ```
+/// [into]: trait.IntoRunCondition.html
+/// [depth]: struct.Depth.html
+/// [logger]: struct.Logger.html
+#[derive(Clone, Copy, Debug)]
+pub struct MinDepth<T> {
+    depth: u32,
+    condition: T,
+}
+
+impl<T> MinDepth<T> {
+    /// Creates a new `MinDepth`, only respecting `condition` once `depth` is reached.
+    pub fn new(depth: u32, condition: T) -> Self {
+        Self { depth, condition }
+    }
+}
+
+/// The [`RunCondition`][rc] created by [`MinDepth`][md]`::into_run_condition`
+///
+/// [rc]: trait.RunCondition.html
+/// [md]: struct.MinDepth.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InnerMinDepth<T> {
+    min: u32,
+    current: u32,
+    condition: T,
+}
+
+impl<T: IntoRunCondition> IntoRunCondition for MinDepth<T> {
+    type RunCondition = InnerMinDepth<T::RunCondition>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerMinDepth {
+            min: self.depth,
+            current: 0,
+            condition: self.condition.into_run_condition(),
+        }
+    }
+}
+
+impl<T: RunCondition> RunCondition for InnerMinDepth<T> {
+    #[inline]
+    fn step(&mut self) -> bool {
+        self.condition.step() || self.current < self.min
+    }
+
+    #[inline]
+    fn depth(&mut self, depth: u32) -> bool {
+        self.current = depth;
+        self.condition.depth(depth) || depth < self.min
+    }
+
+    #[inline]
+    fn stopped(&mut self) {
+        self.condition.stopped();
+    }
+}
+
+/// Takes another [`IntoRunCondition`][into] and additionally stops the search once `flag` is set,
+/// which can be used to abort a search running on a different thread.
+///
+/// A cancelled search returns the best action found so far, just like any other condition.
+/// The bot does not reuse any results of previous searches, so once [`select`][sel] returned,
+/// the same bot can be used to search a different state, for example after the opponent moved.
+/// Remember to reset `flag` before starting the next search.
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::{Bot, Cancellable, ToCompletion, tree::Node};
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::sync::Arc;
+///
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 7).with_children(&[
+///         Node::new(true, 4),
+///         Node::new(true, 2),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 8),
+///         Node::new(true, 9)
+///     ]),
+/// ]);
+///
+/// let flag = Arc::new(AtomicBool::new(false));
+/// let mut bot = Bot::new(true);
+/// // A different thread can call `flag.store(true, Ordering::Relaxed)` to cancel the search.
+/// assert_eq!(bot.select(&tree, Cancellable::new(flag.clone(), ToCompletion)), Some(1));
+///
+/// flag.store(true, Ordering::Relaxed);
+/// // Without a completed depth, the action with the highest `Game::look_ahead` is returned.
+/// assert_eq!(bot.select(&tree, Cancellable::new(flag.clone(), ToCompletion)), Some(0));
+/// ```
+/// [into]: trait.IntoRunCondition.html
+/// [sel]: alpha_beta/struct.Bot.html#method.select
+#[derive(Clone, Debug)]
+pub struct Cancellable<T> {
+    flag: Arc<AtomicBool>,
+    condition: T,
+}
+
+impl<T> Cancellable<T> {
+    /// Creates a new `Cancellable`, which stops once either `flag` is set or `condition` returns `false`.
+    pub fn new(flag: Arc<AtomicBool>, condition: T) -> Self {
+        Self { flag, condition }
+    }
+}
+
+/// The [`RunCondition`][rc] created by [`Cancellable`][cancel]`::into_run_condition`
+///
+/// [rc]: trait.RunCondition.html
+/// [cancel]: struct.Cancellable.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InnerCancellable<T> {
+    flag: Arc<AtomicBool>,
+    condition: T,
+}
+
+impl<T: IntoRunCondition> IntoRunCondition for Cancellable<T> {
+    type RunCondition = InnerCancellable<T::RunCondition>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerCancellable {
+            flag: self.flag,
+            condition: self.condition.into_run_condition(),
+        }
+    }
+}
+
+impl<T: RunCondition> RunCondition for InnerCancellable<T> {
+    #[inline]
+    fn step(&mut self) -> bool {
+        !self.flag.load(Ordering::Relaxed) && self.condition.step()
+    }
+
+    #[inline]
+    fn depth(&mut self, depth: u32) -> bool {
+        !self.flag.load(Ordering::Relaxed) && self.condition.depth(depth)
+    }
+
+    #[inline]
+    fn stopped(&mut self) {
+        self.condition.stopped();
+    }
+}
+
+/// A [`RunCondition`][rc] which can be used by multiple searches at the same time, for example
+/// to limit the total amount of work done by all bots of a server.
+///
+/// As all methods take `&self`, any mutable state has to use atomics or other types with interior mutability.
+/// Wrapping a `SharedCondition` in an [`Arc`][arc] implements `RunCondition`, so each search uses a clone
+/// of the same `Arc`, possibly on a different thread. A search may therefore stop because of steps
+/// done by a different search. The methods are called very often, so they should be cheap,
+/// using a `Mutex` in `step` can easily make all searches a lot slower.
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::{Bot, SharedCondition, tree::Node};
+/// use std::sync::atomic::{AtomicU32, Ordering};
+/// use std::sync::Arc;
+///
+/// /// Allows at most `self.0` steps, shared by all searches.
+/// struct Budget(AtomicU32);
+///
+/// impl SharedCondition for Budget {
+///     fn step(&self) -> bool {
+///         self.0
+///             .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |steps| steps.checked_sub(1))
+///             .is_ok()
+///     }
+///
+///     fn depth(&self, _: u32) -> bool {
+///         self.0.load(Ordering::Relaxed) > 0
+///     }
+/// }
+///
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 7).with_children(&[
+///         Node::new(true, 4),
+///         Node::new(true, 2),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 8),
+///         Node::new(true, 9)
+///     ]),
+/// ]);
+///
+/// let budget = Arc::new(Budget(AtomicU32::new(1000)));
+/// assert_eq!(Bot::new(true).select(&tree, budget.clone()), Some(1));
+/// assert_eq!(Bot::new(true).select(&tree, budget.clone()), Some(1));
+///
+/// // Once the budget is exhausted, all searches stop.
+/// budget.0.store(0, Ordering::Relaxed);
+/// assert_eq!(Bot::new(true).select(&tree, budget), Some(0));
+/// ```
+/// [rc]: trait.RunCondition.html
+/// [arc]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+pub trait SharedCondition: Send + Sync {
+    /// Called at each search step of every search using `self`, instantly stops the current search by returning `false`.
+    fn step(&self) -> bool;
+    /// Called after every finished search depth of every search using `self`,
+    /// instantly stops the current search by returning `false`.
+    fn depth(&self, depth: u32) -> bool;
+
+    /// Called in case a search was stopped by a condition wrapping `self`, even though `self` would have continued.
+    ///
+    /// The default implementation does nothing.
+    #[inline]
+    fn stopped(&self) {}
+}
+
+impl<T: SharedCondition + ?Sized> RunCondition for Arc<T> {
+    #[inline]
+    fn step(&mut self) -> bool {
+        SharedCondition::step(&**self)
+    }
+
+    #[inline]
+    fn depth(&mut self, depth: u32) -> bool {
+        SharedCondition::depth(&**self, depth)
+    }
+
+    #[inline]
+    fn stopped(&mut self) {
+        SharedCondition::stopped(&**self)
+    }
 }
 
 /// Returns `true` while the `Instant` is still in the future
@@ -447,9 +1233,14 @@ impl RunCondition for ToCompletion {
     }
 }
 
-/// A struct implementing [`RunCondition`][rc] returning cancelling the computation once the depth `self.0`
+/// A struct implementing [`RunCondition`][rc] cancelling the computation once the depth `self.0`
 /// is reached.
 ///
+/// `Depth(n)` looks `n + 1` actions ahead, including the action which is selected.
+/// `Depth(0)` therefore only compares the fitness of each possible action, while `Depth(1)` also considers the
+/// best reply to each of them. To do so, the bot completes the depths `0` to `n - 1`, where depth `d`
+/// looks `d + 2` actions ahead, so [`Logger::depth`][ld] returns `n` afterwards.
+///
 /// # Examples
 ///
 /// ```rust
@@ -466,10 +1257,13 @@ impl RunCondition for ToCompletion {
 /// ]);
 ///
 /// let mut bot = Bot::new(true);
+/// // Only looks at the fitness of the two possible actions.
 /// assert_eq!(bot.select(&tree, Depth(0)), Some(0));
+/// // Also looks at the replies of the opponent.
 /// assert_eq!(bot.select(&tree, Depth(1)), Some(1));
 /// ```
 /// [rc]: trait.RunCondition.html
+/// [ld]: struct.Logger.html#method.depth
 #[derive(Clone, Copy, Debug)]
 pub struct Depth(pub u32);
 
@@ -515,10 +1309,12 @@ impl RunCondition for Depth {
 /// [sel]: alpha_beta/struct.Bot.html#method.select
 pub struct Logger<T: IntoRunCondition> {
     condition: T::RunCondition,
-    steps: u32,
+    steps: u64,
     depth: u32,
     completed: bool,
     duration: Duration,
+    depth_timings: Vec<(u32, Duration)>,
+    accumulate: bool,
 }
 
 impl<T: IntoRunCondition> Debug for Logger<T>
@@ -532,6 +1328,8 @@ where
             .field("depth", &self.depth)
             .field("completed", &self.completed)
             .field("duration", &self.duration)
+            .field("depth_timings", &self.depth_timings)
+            .field("accumulate", &self.accumulate)
             .finish()
     }
 }
@@ -545,14 +1343,66 @@ impl<T: IntoRunCondition> Logger<T> {
             depth: 0,
             completed: true,
             duration: Duration::from_secs(0),
+            depth_timings: Vec::new(),
+            accumulate: false,
         }
     }
 
-    /// Returns the total amount of times [`step`][step] was called during the last call to [`select`][sel].
+    /// Sums up [`steps`][steps] and [`duration`][dur] over all calls to [`select`][sel]
+    /// until [`reset`][reset] is called, instead of only storing them for the last call.
     ///
+    /// This can be used to measure an entire game instead of a single action.
+    /// [`depth`][depth], [`completed`][completed] and [`depth_timings`][timings] still only refer to the last call.
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, tree::Node, ToCompletion, Logger};
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 6),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// let mut logger = Logger::new(ToCompletion).accumulating();
+    /// bot.select(&tree, &mut logger);
+    /// let steps = logger.steps();
+    /// bot.select(&tree, &mut logger);
+    /// assert_eq!(logger.steps(), 2 * steps);
+    ///
+    /// logger.reset();
+    /// assert_eq!(logger.steps(), 0);
+    /// ```
+    /// [steps]: struct.Logger.html#method.steps
+    /// [dur]: struct.Logger.html#method.duration
+    /// [depth]: struct.Logger.html#method.depth
+    /// [completed]: struct.Logger.html#method.completed
+    /// [timings]: struct.Logger.html#method.depth_timings
+    /// [reset]: struct.Logger.html#method.reset
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    pub fn accumulating(mut self) -> Self {
+        self.accumulate = true;
+        self
+    }
+
+    /// Resets all recorded information, as if `self` was never used.
+    pub fn reset(&mut self) {
+        self.steps = 0;
+        self.depth = 0;
+        self.completed = true;
+        self.duration = Duration::from_secs(0);
+        self.depth_timings.clear();
+    }
+
+    /// Returns the total amount of times [`step`][step] was called during the last call to [`select`][sel],
+    /// or since the last [`reset`][reset] if `self` is [`accumulating`][acc].
+    ///
+    /// [reset]: struct.Logger.html#method.reset
+    /// [acc]: struct.Logger.html#method.accumulating
     /// [step]: trait.RunCondition.html#tymethod.step
     /// [sel]: alpha_beta/struct.Bot.html#method.select
-    pub fn steps(&self) -> u32 {
+    pub fn steps(&self) -> u64 {
         self.steps
     }
 
@@ -571,13 +1421,42 @@ impl<T: IntoRunCondition> Logger<T> {
         self.completed
     }
 
-    /// Returns the total time spend during the last call to [`select`][sel].
+    /// Returns the total time spend during the last call to [`select`][sel],
+    /// or since the last [`reset`][reset] if `self` is [`accumulating`][acc].
     ///
+    /// [reset]: struct.Logger.html#method.reset
+    /// [acc]: struct.Logger.html#method.accumulating
     /// [sel]: alpha_beta/struct.Bot.html#method.select
     pub fn duration(&self) -> Duration {
         self.duration
     }
 
+    /// Returns how long each depth took during the last call to [`select`][sel], in the order they were searched.
+    ///
+    /// In case the search was cancelled while searching a depth, the last entry is the time spent
+    /// on this unfinished depth. The growth of these durations shows the effective branching factor,
+    /// a better move ordering results in slower growth.
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, tree::Node, Depth, Logger};
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 6),
+    /// ]);
+    ///
+    /// let mut logger = Logger::new(Depth(2));
+    /// Bot::new(true).select(&tree, &mut logger);
+    /// let depths: Vec<u32> = logger.depth_timings().iter().map(|&(depth, _)| depth).collect();
+    /// assert_eq!(depths, &[0, 1]);
+    /// ```
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    pub fn depth_timings(&self) -> &[(u32, Duration)] {
+        &self.depth_timings
+    }
+
     /// consumes `self` and returns the wrapped `condition`
     pub fn into_inner(self) -> T::RunCondition {
         self.condition
@@ -589,15 +1468,29 @@ impl<T: IntoRunCondition> Logger<T> {
 /// [rc]: trait.RunCondition.html
 /// [logger]: struct.Logger.html
 #[doc(hidden)]
-pub struct InnerLogger<'a, T: IntoRunCondition>(&'a mut Logger<T>, Instant);
+pub struct InnerLogger<'a, T: IntoRunCondition>(&'a mut Logger<T>, Instant, Option<(u32, Instant)>);
+
+impl<'a, T: IntoRunCondition> InnerLogger<'a, T> {
+    /// Records the duration of the currently searched depth.
+    fn finish_depth(&mut self) {
+        if let Some((depth, start)) = self.2.take() {
+            self.0.depth_timings.push((depth, start.elapsed()));
+        }
+    }
+}
 
 impl<'a, T: IntoRunCondition> IntoRunCondition for &'a mut Logger<T> {
     type RunCondition = InnerLogger<'a, T>;
 
     fn into_run_condition(self) -> InnerLogger<'a, T> {
-        self.steps = 0;
+        if !self.accumulate {
+            self.steps = 0;
+            self.duration = Duration::from_secs(0);
+        }
         self.depth = 0;
-        InnerLogger(self, Instant::now())
+        self.completed = true;
+        self.depth_timings.clear();
+        InnerLogger(self, Instant::now(), None)
     }
 }
 
@@ -605,7 +1498,7 @@ impl<'a, T: IntoRunCondition> RunCondition for InnerLogger<'a, T> {
     #[inline]
     fn step(&mut self) -> bool {
         self.0.steps += 1;
-        if self.0.condition.step() {
+        if RunCondition::step(&mut self.0.condition) {
             true
         } else {
             self.0.completed = false;
@@ -615,20 +1508,30 @@ impl<'a, T: IntoRunCondition> RunCondition for InnerLogger<'a, T> {
 
     #[inline]
     fn depth(&mut self, depth: u32) -> bool {
+        self.finish_depth();
         self.0.depth = depth;
-        if self.0.condition.depth(depth) {
+        if RunCondition::depth(&mut self.0.condition, depth) {
+            self.2 = Some((depth, Instant::now()));
             true
         } else {
             self.0.completed = false;
             false
         }
     }
+
+    #[inline]
+    fn stopped(&mut self) {
+        self.0.completed = false;
+        self.0.condition.stopped();
+    }
 }
 
 impl<'a, T: IntoRunCondition> Drop for InnerLogger<'a, T> {
     fn drop(&mut self) {
-        self.0.duration = self.1.elapsed();
+        self.finish_depth();
+        self.0.duration += self.1.elapsed();
     }
 }
 
 pub use alpha_beta::Bot;
+pub use fixed::Fixed;
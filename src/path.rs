@@ -0,0 +1,91 @@
+//! Searches for a sequence of actions reaching a given state, instead of the best possible action.
+use crate::Game;
+
+/// Returns the shortest sequence of actions from `state` to a state for which `goal` returns `true`,
+/// or `None` if no such state can be reached in at most `max_depth` actions.
+///
+/// This uses iterative deepening, so it only stores the current path and does not need to hash states.
+/// Unlike [`Bot::select`][sel], this ignores which player is active and executes every action
+/// returned by [`Game::actions`][actions] for `player`. The resulting path may therefore contain
+/// actions of other players, which are assumed to cooperate. States for which [`Game::is_terminal`][term]
+/// or [`Game::move_limit_reached`][limit] return `true` are not searched any further.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::ops::Range;
+///
+/// /// Reach a number by either adding 1 or doubling it.
+/// #[derive(Clone)]
+/// struct Counter(u32);
+///
+/// impl rubot::Game for Counter {
+///     type Player = ();
+///     /// `0` adds 1, `1` doubles the number.
+///     type Action = u8;
+///     type Fitness = u32;
+///     type Actions = Range<u8>;
+///
+///     fn actions(&self, _: ()) -> (bool, Range<u8>) {
+///         (true, 0..2)
+///     }
+///
+///     fn execute(&mut self, action: &u8, _: ()) -> u32 {
+///         self.0 = if *action == 0 { self.0 + 1 } else { self.0 * 2 };
+///         self.0
+///     }
+/// }
+///
+/// // 3 + 1 = 4, 4 + 1 = 5, 5 * 2 = 10
+/// let path = rubot::path::shortest_path_to(&Counter(3), (), |c| c.0 == 10, 10);
+/// assert_eq!(path, Some(vec![0, 0, 1]));
+/// assert_eq!(rubot::path::shortest_path_to(&Counter(3), (), |c| c.0 == 100, 3), None);
+/// ```
+/// [sel]: ../alpha_beta/struct.Bot.html#method.select
+/// [actions]: ../trait.Game.html#tymethod.actions
+/// [term]: ../trait.Game.html#method.is_terminal
+/// [limit]: ../trait.Game.html#method.move_limit_reached
+pub fn shortest_path_to<T: Game>(
+    state: &T,
+    player: T::Player,
+    goal: impl Fn(&T) -> bool,
+    max_depth: u32,
+) -> Option<Vec<T::Action>> {
+    let mut path = Vec::new();
+    for depth in 0..=max_depth {
+        if search(state, player, &goal, depth, &mut path) {
+            // `path` is used as a stack, with the last action being the first one.
+            path.reverse();
+            return Some(path);
+        }
+    }
+
+    None
+}
+
+/// Searches for a goal state exactly `depth` actions away from `state`.
+fn search<T: Game>(
+    state: &T,
+    player: T::Player,
+    goal: &impl Fn(&T) -> bool,
+    depth: u32,
+    path: &mut Vec<T::Action>,
+) -> bool {
+    if depth == 0 {
+        return goal(state);
+    } else if state.is_terminal() || state.move_limit_reached() {
+        return false;
+    }
+
+    let (_, actions) = state.actions(player);
+    for action in actions {
+        let mut next = state.clone();
+        next.execute(&action, player);
+        if search(&next, player, goal, depth - 1, path) {
+            path.push(action);
+            return true;
+        }
+    }
+
+    false
+}
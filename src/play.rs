@@ -0,0 +1,90 @@
+//! Helpers for complete games instead of single positions.
+use crate::{Bot, Game, IntoFitnessCondition};
+
+/// The result of a finished game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome<P> {
+    /// The game was won by the given player.
+    Win(P),
+    /// The game ended without a winner.
+    Draw,
+}
+
+impl<P> Outcome<P> {
+    /// Returns the outcome of the finished game `state`, using [`Game::winner`][winner].
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "games")] {
+    /// use rubot::games::{Nim, Player};
+    /// use rubot::play::Outcome;
+    ///
+    /// let mut nim = Nim::new(2, 2);
+    /// rubot::Game::execute(&mut nim, &2, Player::First);
+    /// assert_eq!(Outcome::of(&nim), Outcome::Win(Player::First));
+    /// # }
+    /// ```
+    /// [winner]: ../trait.Game.html#method.winner
+    pub fn of<T: Game<Player = P>>(state: &T) -> Self {
+        state.winner().map_or(Outcome::Draw, Outcome::Win)
+    }
+}
+
+/// Plays a complete game starting at `initial`, returning all executed actions and the [`Outcome`][outcome].
+///
+/// Each turn, the bot which is the active player according to [`Game::actions`][actions] selects
+/// and executes an action using a clone of `condition`. Players do not have to alternate,
+/// so a player may execute multiple actions in a row. The game ends once neither bot can select an action.
+/// The outcome is computed using [`Outcome::of`][of], so it is always [`Outcome::Draw`][draw]
+/// if [`Game::winner`][winner] is not implemented.
+///
+/// This never returns if the game does not end.
+///
+/// # Panics
+///
+/// Panics if both bots are the active player at the same time.
+///
+/// ```rust
+/// # #[cfg(feature = "games")] {
+/// use rubot::{Bot, ToCompletion, games::{Nim, Player}};
+/// use rubot::play::{self, Outcome};
+///
+/// let mut first = Bot::new(Player::First);
+/// let mut second = Bot::new(Player::Second);
+/// let (actions, outcome) = play::self_play(Nim::new(5, 3), &mut first, &mut second, ToCompletion);
+/// // The first player leaves a multiple of 4 by taking one flag.
+/// assert_eq!(actions[0], 1);
+/// assert_eq!(actions.iter().sum::<u32>(), 5);
+/// assert_eq!(outcome, Outcome::Win(Player::First));
+/// # }
+/// ```
+/// [outcome]: enum.Outcome.html
+/// [actions]: ../trait.Game.html#tymethod.actions
+/// [of]: enum.Outcome.html#method.of
+/// [draw]: enum.Outcome.html#variant.Draw
+/// [winner]: ../trait.Game.html#method.winner
+pub fn self_play<T: Game, U: IntoFitnessCondition<T::Fitness> + Clone>(
+    initial: T,
+    a: &mut Bot<T>,
+    b: &mut Bot<T>,
+    condition: U,
+) -> (Vec<T::Action>, Outcome<T::Player>) {
+    let mut state = initial;
+    let mut actions = Vec::new();
+    loop {
+        let (bot, action) = match a.select(&state, condition.clone()) {
+            Some(action) => {
+                assert!(
+                    b.select(&state, condition.clone()).is_none(),
+                    "both bots are the active player"
+                );
+                (&*a, action)
+            }
+            None => match b.select(&state, condition.clone()) {
+                Some(action) => (&*b, action),
+                None => return (actions, Outcome::of(&state)),
+            },
+        };
+        state.execute(&action, bot.player());
+        actions.push(action);
+    }
+}
@@ -0,0 +1,46 @@
+//! Converts floating point heuristics into integer fitnesses, which implement `Ord`.
+//!
+//! Both functions are deterministic, so the same heuristic always results in the same fitness.
+//! For a fixed-point type which can be used directly, see [`Fixed`][fixed].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rubot::quantize::{dequantize, quantize};
+//!
+//! // Store evaluations in centipawns.
+//! const FACTOR: f64 = 100.0;
+//!
+//! let fitness = quantize(1.234, FACTOR);
+//! assert_eq!(fitness, 123);
+//! assert_eq!(dequantize(fitness, FACTOR), 1.23);
+//! ```
+//! [fixed]: ../fixed/struct.Fixed.html
+
+/// Returns `value * factor`, rounded to the nearest integer.
+///
+/// Halfway cases are rounded away from zero. Values which are too large for an `i32` saturate
+/// at `i32::MAX` and `i32::MIN` respectively, while `NaN` results in `0`.
+///
+/// ```rust
+/// use rubot::quantize::quantize;
+///
+/// assert_eq!(quantize(0.125, 100.0), 13);
+/// assert_eq!(quantize(-0.125, 100.0), -13);
+/// assert_eq!(quantize(1e12, 100.0), i32::MAX);
+/// assert_eq!(quantize(f64::NEG_INFINITY, 100.0), i32::MIN);
+/// assert_eq!(quantize(f64::NAN, 100.0), 0);
+/// ```
+pub fn quantize(value: f64, factor: f64) -> i32 {
+    // Casting a float to an integer saturates and maps `NaN` to `0`.
+    (value * factor).round() as i32
+}
+
+/// Converts a fitness created by [`quantize`][quantize] back into a float, for example to display it.
+///
+/// This is exact up to the rounding of `quantize`, as long as the value did not saturate.
+///
+/// [quantize]: fn.quantize.html
+pub fn dequantize(fitness: i32, factor: f64) -> f64 {
+    f64::from(fitness) / factor
+}
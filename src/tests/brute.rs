@@ -1,6 +1,8 @@
 //! testing tests, deep!
 use super::*;
+use crate::alpha_beta::Action;
 use crate::brute::Brute;
+use crate::Game;
 
 #[test]
 fn allowed_actions_depth_zero() {
@@ -26,3 +28,1095 @@ fn allowed_actions_depth_zero() {
         }
     }
 }
+
+fn random_tree(seed: u32) -> Node {
+    let mut bytes = seed.to_be_bytes().to_vec();
+    bytes.extend((0..16).map(|i| (seed.wrapping_mul(73) ^ (i * 37)) as u8));
+    Node::from_bytes(&bytes)
+}
+
+/// The depths compared with brute force, `u32::MAX` searches the complete tree.
+const DEPTHS: [u32; 5] = [0, 1, 2, 3, u32::MAX];
+
+/// Selects an action of `state` using `bot` and asserts that it is the best action of `reference`,
+/// which is the game the bot is expected to play, when looking `depth` actions ahead.
+///
+/// Forced actions are not searched, so their fitness is only checked if there are multiple root actions.
+fn assert_best<G, R>(
+    brute: &mut Brute<R>,
+    bot: &mut Bot<G>,
+    state: &G,
+    reference: &R,
+    depth: u32,
+    seed: u32,
+) -> Option<Action<G>>
+where
+    G: Game<Player = bool, Action = usize, Fitness = i8>,
+    R: Game<Player = bool, Action = usize, Fitness = i8>,
+{
+    let selected = bot.detailed_select(state, Depth(depth));
+    let action = selected.as_ref().map(|act| act.path[0]);
+    assert!(
+        brute.check_if_best(reference, action.as_ref(), depth),
+        "seed: {}, depth: {}",
+        seed,
+        depth
+    );
+    if let (Some(selected), Some(action)) = (&selected, action) {
+        if bot.last_root_actions().len() > 1 {
+            assert_eq!(
+                selected.fitness,
+                brute.minimax(reference, &action, depth),
+                "seed: {}, depth: {}",
+                seed,
+                depth
+            );
+        }
+    }
+    selected
+}
+
+/// Calls `assert_best` for each of `DEPTHS`, using a new bot returned by `bot(depth)` each time.
+///
+/// Returns the selected root actions.
+fn assert_matches_brute<G, R>(
+    state: &G,
+    reference: &R,
+    mut bot: impl FnMut(u32) -> Bot<G>,
+    seed: u32,
+) -> Vec<Option<usize>>
+where
+    G: Game<Player = bool, Action = usize, Fitness = i8>,
+    R: Game<Player = bool, Action = usize, Fitness = i8>,
+{
+    let mut brute = Brute::new(true);
+    DEPTHS
+        .iter()
+        .map(|&depth| {
+            assert_best(&mut brute, &mut bot(depth), state, reference, depth, seed)
+                .map(|act| act.path[0])
+        })
+        .collect()
+}
+
+/// The search is fail-soft, which must not change the selected action or its fitness.
+#[test]
+fn fail_soft_fitness() {
+    for seed in 0..500 {
+        let tree = random_tree(seed);
+        if tree.child_count() < 2 {
+            // Forced actions are not searched.
+            continue;
+        }
+        let mut brute = Brute::new(true);
+
+        let completed = Bot::new(true).detailed_select(&tree, ToCompletion);
+        assert!(brute.check_if_best(&tree, completed.as_ref().map(|act| &act.path[0]), u32::MAX));
+        if let Some(action) = completed {
+            assert!(action.exact, "seed: {}", seed);
+            assert_eq!(
+                action.fitness,
+                brute.minimax(&tree, &action.path[0], u32::MAX),
+                "seed: {}",
+                seed
+            );
+        }
+
+        for depth in 0..4 {
+            let selected = Bot::new(true).detailed_select(&tree, Depth(depth));
+            assert!(brute.check_if_best(&tree, selected.as_ref().map(|act| &act.path[0]), depth));
+            if let Some(action) = selected {
+                assert_eq!(action.exact, depth > 0, "seed: {}, depth: {}", seed, depth);
+                // The search stops early once the result is proven.
+                if action.terminal {
+                    assert!(action.depth <= depth.checked_sub(1), "seed: {}", seed);
+                } else {
+                    assert_eq!(action.depth, depth.checked_sub(1), "seed: {}", seed);
+                }
+                assert_eq!(
+                    action.fitness,
+                    brute.minimax(&tree, &action.path[0], depth),
+                    "seed: {}, depth: {}",
+                    seed,
+                    depth
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn evaluate_actions() {
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        let mut brute = Brute::new(true);
+
+        let evaluated = Bot::new(true).evaluate_actions(&tree, ToCompletion);
+        assert_eq!(evaluated.len(), tree.child_count());
+        for (action, fitness) in evaluated.iter() {
+            assert_eq!(
+                *fitness,
+                brute.minimax(&tree, action, u32::MAX),
+                "seed: {}",
+                seed
+            );
+        }
+        assert!(evaluated.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn evaluate_actions_parallel() {
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        let mut bot = Bot::new(true);
+        assert_eq!(
+            bot.evaluate_actions_parallel(&tree, ToCompletion),
+            bot.evaluate_actions(&tree, ToCompletion),
+            "seed: {}",
+            seed
+        );
+        assert_eq!(
+            bot.evaluate_actions_parallel(&tree, Depth(1)),
+            bot.evaluate_actions(&tree, Depth(1)),
+            "seed: {}",
+            seed
+        );
+
+        // Only `select` remembers pruned actions.
+        bot.select(&tree, Depth(1));
+        bot.evaluate_actions_parallel(&tree, Depth(1));
+        assert!(bot.last_pruned_actions().is_empty(), "seed: {}", seed);
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn select_batch() {
+    let trees: Vec<_> = (0..200).map(random_tree).collect();
+    for &depth in DEPTHS.iter() {
+        let expected: Vec<_> = trees
+            .iter()
+            .map(|tree| Bot::new(true).select(tree, Depth(depth)))
+            .collect();
+        let mut bot = Bot::new(true);
+        bot.select(&trees[0], Depth(depth));
+        assert_eq!(
+            bot.select_batch(&trees, Depth(depth)),
+            expected,
+            "depth: {}",
+            depth
+        );
+        assert!(bot.last_pruned_actions().is_empty(), "depth: {}", depth);
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn select_parallel() {
+    use crate::alpha_beta::TieBreak;
+
+    for seed in 0..200 {
+        let tree = tied_tree(seed);
+        let mut brute = Brute::new(true);
+        for &threads in [1, 2, 3, 8].iter() {
+            for &tie_break in [TieBreak::First, TieBreak::Mobility, TieBreak::Random(7)].iter() {
+                let selected = Bot::new(true).with_tie_break(tie_break).select_parallel(
+                    &tree,
+                    ToCompletion,
+                    threads,
+                );
+                assert!(
+                    brute.check_if_best(&tree, selected.as_ref(), u32::MAX),
+                    "seed: {}, threads: {}, tie break: {:?}",
+                    seed,
+                    threads,
+                    tie_break
+                );
+            }
+            for &depth in [0, 1, 2, 3].iter() {
+                let selected = Bot::new(true).select_parallel(&tree, Depth(depth), threads);
+                assert!(
+                    brute.check_if_best(&tree, selected.as_ref(), depth),
+                    "seed: {}, threads: {}, depth: {}",
+                    seed,
+                    threads,
+                    depth
+                );
+            }
+        }
+    }
+}
+
+/// With the same action found first, `select_parallel` breaks ties just like `select`.
+///
+/// The action found first may differ, as the threads prune the tree differently than `select`.
+/// This is also why only fixed depths are compared, as this may cause the tree to be exhausted at a different depth.
+#[cfg(feature = "parallel")]
+#[test]
+fn select_parallel_tie_break() {
+    use crate::alpha_beta::TieBreak;
+
+    let mut same_first = 0;
+    let mut compared = 0;
+    for seed in 0..200 {
+        let tree = tied_tree(seed);
+        let mut brute = Brute::new(true);
+        for &threads in [2, 3, 8].iter() {
+            for &depth in [0, 1, 2, 3].iter() {
+                let first = Bot::new(true).select(&tree, Depth(depth));
+                let selected = Bot::new(true).select_parallel(&tree, Depth(depth), threads);
+                assert!(
+                    brute.check_if_best(&tree, selected.as_ref(), depth),
+                    "seed: {}, threads: {}, depth: {}",
+                    seed,
+                    threads,
+                    depth
+                );
+                compared += 1;
+                if first != selected {
+                    continue;
+                }
+                same_first += 1;
+
+                let bots: [fn() -> Bot<Node>; 4] = [
+                    || Bot::new(true).with_tie_break(TieBreak::Mobility),
+                    || Bot::new(true).with_tie_break(TieBreak::Random(7)),
+                    || Bot::new(true).with_tie_break(TieBreak::Predicate(|_, &act, _| act == 1)),
+                    || {
+                        Bot::new(true)
+                            .with_tie_break(TieBreak::Random(7))
+                            .with_fitness_blur(1)
+                    },
+                ];
+                for (i, bot) in bots.iter().enumerate() {
+                    assert_eq!(
+                        bot().select_parallel(&tree, Depth(depth), threads),
+                        bot().select(&tree, Depth(depth)),
+                        "seed: {}, threads: {}, depth: {}, bot: {}",
+                        seed,
+                        threads,
+                        depth,
+                        i
+                    );
+                }
+            }
+        }
+    }
+    assert!(
+        same_first * 10 > compared * 9,
+        "{} of {}",
+        same_first,
+        compared
+    );
+}
+
+#[test]
+fn terminal_path() {
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+
+        let action = Bot::new(true).detailed_select(&tree, ToCompletion).unwrap();
+        assert!(action.terminal, "seed: {}", seed);
+        let mut node = tree.clone();
+        for child in action.path.iter() {
+            node.execute(child, true);
+        }
+        assert!(node.is_leaf(), "seed: {}", seed);
+
+        if let Some(action) = Bot::new(true).detailed_select(&tree, Depth(0)) {
+            let mut node = tree.clone();
+            node.execute(&action.path[0], true);
+            assert!(!action.terminal || node.is_leaf(), "seed: {}", seed);
+        }
+    }
+}
+
+#[test]
+fn max_depth() {
+    let path_and_fitness =
+        |act: Option<crate::alpha_beta::Action<Node>>| act.map(|act| (act.path, act.fitness));
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        for depth in 0..4 {
+            let expected = Bot::new(true).detailed_select(&tree, Depth(depth));
+            let selected = Bot::new(true)
+                .with_max_depth(depth)
+                .detailed_select(&tree, ToCompletion);
+            assert_eq!(
+                path_and_fitness(expected),
+                path_and_fitness(selected),
+                "seed: {}, depth: {}",
+                seed,
+                depth
+            );
+
+            let fixed = Bot::new(true)
+                .with_max_depth(depth)
+                .with_fixed_depth(depth + 2)
+                .detailed_select(&tree, ToCompletion);
+            let expected = Bot::new(true)
+                .with_fixed_depth(depth)
+                .detailed_select(&tree, ToCompletion);
+            assert_eq!(
+                path_and_fitness(expected),
+                path_and_fitness(fixed),
+                "seed: {}, depth: {}",
+                seed,
+                depth
+            );
+        }
+    }
+}
+
+#[test]
+fn fixed_depth() {
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        assert_matches_brute(
+            &tree,
+            &tree,
+            |depth| Bot::new(true).with_fixed_depth(depth),
+            seed,
+        );
+    }
+}
+
+/// Searches the last child of each node first.
+#[derive(Clone)]
+struct Hinted(Node);
+
+impl Game for Hinted {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = <Node as Game>::Actions;
+
+    fn actions(&self, player: bool) -> (bool, Self::Actions) {
+        self.0.actions(player)
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i8 {
+        self.0.execute(action, player)
+    }
+
+    fn hint_action(&self, _: bool) -> Option<usize> {
+        self.0.child_count().checked_sub(1)
+    }
+}
+
+#[test]
+fn hint_action() {
+    for seed in 0..200 {
+        let tree = Hinted(random_tree(seed));
+        assert_matches_brute(&tree, &tree, |_| Bot::new(true), seed);
+    }
+}
+
+#[test]
+fn select_with_prior() {
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        let mut brute = Brute::new(true);
+        for prior in 0..tree.child_count() {
+            for &depth in DEPTHS.iter() {
+                let selected = Bot::new(true).select_with_prior(&tree, Depth(depth), prior);
+                assert!(
+                    brute.check_if_best(&tree, selected.as_ref(), depth),
+                    "seed: {}, depth: {}, prior: {}",
+                    seed,
+                    depth,
+                    prior
+                );
+            }
+        }
+    }
+}
+
+/// Stops at every node with a fitness divisible by 3, using half of its fitness instead.
+#[derive(Clone)]
+struct Resigning(Node);
+
+impl Game for Resigning {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = <Node as Game>::Actions;
+
+    fn actions(&self, player: bool) -> (bool, Self::Actions) {
+        self.0.actions(player)
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i8 {
+        self.0.execute(action, player)
+    }
+
+    fn terminal_value(&self, player: bool) -> Option<i8> {
+        let fitness = self.0.terminal_fitness(player).unwrap();
+        if fitness % 3 == 0 {
+            Some(fitness / 2)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn terminal_value() {
+    for seed in 0..200 {
+        let tree = Resigning(random_tree(seed));
+        let mut brute = Brute::new(true);
+        for (action, fitness) in Bot::new(true).evaluate_actions(&tree, ToCompletion) {
+            assert_eq!(
+                fitness,
+                brute.minimax(&tree, &action, u32::MAX),
+                "seed: {}",
+                seed
+            );
+        }
+
+        assert_matches_brute(&tree, &tree, |_| Bot::new(true), seed);
+    }
+}
+
+/// Uses a static evaluation which differs from the fitness of the node.
+#[derive(Clone)]
+struct Evaluated(Node);
+
+impl Game for Evaluated {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = <Node as Game>::Actions;
+
+    fn actions(&self, player: bool) -> (bool, Self::Actions) {
+        self.0.actions(player)
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i8 {
+        self.0.execute(action, player)
+    }
+
+    fn static_eval(&self, player: bool) -> Option<i8> {
+        Some(-(self.0.terminal_fitness(player).unwrap() / 2))
+    }
+}
+
+#[test]
+fn static_eval() {
+    for seed in 0..200 {
+        let tree = Evaluated(random_tree(seed));
+        assert_matches_brute(&tree, &tree, |_| Bot::new(true), seed);
+    }
+}
+
+/// Splits the fitness into a primary and a secondary score, which are compared lexicographically.
+#[derive(Clone)]
+struct Compound(Node);
+
+impl Compound {
+    fn fitness(fitness: i8) -> (i8, u8) {
+        (fitness.div_euclid(4), fitness.rem_euclid(4) as u8)
+    }
+}
+
+impl Game for Compound {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = (i8, u8);
+    type Actions = <Node as Game>::Actions;
+
+    fn actions(&self, player: bool) -> (bool, Self::Actions) {
+        self.0.actions(player)
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> (i8, u8) {
+        Compound::fitness(self.0.execute(action, player))
+    }
+}
+
+/// The secondary score has to break ties at every depth, not only at the root.
+#[test]
+fn compound_fitness() {
+    let mut secondary_ties = 0;
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        let compound = Compound(tree.clone());
+
+        let evaluated = Bot::new(true).evaluate_actions(&compound, ToCompletion);
+        assert_eq!(
+            evaluated,
+            Bot::new(true)
+                .evaluate_actions(&tree, ToCompletion)
+                .into_iter()
+                .map(|(action, fitness)| (action, Compound::fitness(fitness)))
+                .collect::<Vec<_>>(),
+            "seed: {}",
+            seed
+        );
+        if let [(_, best), (_, second), ..] = evaluated.as_slice() {
+            if best.0 == second.0 && best.1 != second.1 {
+                secondary_ties += 1;
+            }
+        }
+
+        for &depth in DEPTHS.iter() {
+            assert_eq!(
+                Bot::new(true)
+                    .detailed_select(&compound, Depth(depth))
+                    .map(|act| (act.path, act.fitness)),
+                Bot::new(true)
+                    .detailed_select(&tree, Depth(depth))
+                    .map(|act| (act.path, Compound::fitness(act.fitness))),
+                "seed: {}, depth: {}",
+                seed,
+                depth
+            );
+        }
+    }
+    assert!(secondary_ties > 0);
+}
+
+/// Uses fitnesses close to the limits of `i32`, including `i32::MIN` and `i32::MAX`.
+#[derive(Clone)]
+struct Extreme(Node);
+
+impl Extreme {
+    fn fitness(fitness: i8) -> i32 {
+        if fitness < 0 {
+            i32::MIN + (i32::from(fitness) + 128)
+        } else {
+            i32::MAX - (127 - i32::from(fitness))
+        }
+    }
+}
+
+impl Game for Extreme {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i32;
+    type Actions = <Node as Game>::Actions;
+
+    fn actions(&self, player: bool) -> (bool, Self::Actions) {
+        self.0.actions(player)
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i32 {
+        Extreme::fitness(self.0.execute(action, player))
+    }
+
+    fn is_upper_bound(&self, fitness: i32, _: bool) -> bool {
+        fitness == i32::MAX
+    }
+
+    fn is_lower_bound(&self, fitness: i32, _: bool) -> bool {
+        fitness == i32::MIN
+    }
+}
+
+#[test]
+fn extreme_fitness() {
+    let mut extremes = 0;
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        let extreme = Extreme(tree.clone());
+
+        let evaluated: Vec<_> = Bot::new(true)
+            .evaluate_actions(&tree, ToCompletion)
+            .into_iter()
+            .map(|(action, fitness)| (action, Extreme::fitness(fitness)))
+            .collect();
+        assert_eq!(
+            Bot::new(true).evaluate_actions(&extreme, ToCompletion),
+            evaluated,
+            "seed: {}",
+            seed
+        );
+        extremes += evaluated
+            .iter()
+            .filter(|&&(_, fitness)| fitness == i32::MIN || fitness == i32::MAX)
+            .count();
+
+        for &depth in DEPTHS.iter() {
+            assert_eq!(
+                Bot::new(true)
+                    .detailed_select(&extreme, Depth(depth))
+                    .map(|act| (act.path, act.fitness)),
+                Bot::new(true)
+                    .detailed_select(&tree, Depth(depth))
+                    .map(|act| (act.path, Extreme::fitness(act.fitness))),
+                "seed: {}, depth: {}",
+                seed,
+                depth
+            );
+        }
+    }
+    assert!(extremes > 0);
+}
+
+/// Generates a tree with only a few different fitness values, so there are a lot of ties.
+fn tied_tree(seed: u32) -> Node {
+    fn generate(rng: &mut u32, depth: u32) -> Node {
+        let mut next = || {
+            *rng = rng.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            *rng >> 16
+        };
+        let mut node = Node::new(next() % 2 == 0, (next() % 3) as i8);
+        if depth > 0 {
+            for _ in 0..next() % 4 {
+                node.push_child(generate(rng, depth - 1));
+            }
+        }
+        node
+    }
+
+    let mut rng = seed;
+    let mut root = Node::root();
+    for _ in 0..3 {
+        root.push_child(generate(&mut rng, 3));
+    }
+    root
+}
+
+/// Breaking ties must never select a worse action.
+#[test]
+fn tie_break_mobility() {
+    use crate::alpha_beta::TieBreak;
+
+    let mut changed = 0;
+    for seed in 0..300 {
+        let tree = tied_tree(seed);
+        let first = assert_matches_brute(&tree, &tree, |_| Bot::new(true), seed);
+        let selected = assert_matches_brute(
+            &tree,
+            &tree,
+            |_| Bot::new(true).with_tie_break(TieBreak::Mobility),
+            seed,
+        );
+        changed += first.iter().zip(&selected).filter(|(a, b)| a != b).count();
+    }
+    assert!(changed > 0);
+}
+
+#[test]
+fn tie_break_predicate() {
+    use crate::alpha_beta::TieBreak;
+
+    let mut changed = 0;
+    for seed in 0..300 {
+        let tree = tied_tree(seed);
+        let first = assert_matches_brute(&tree, &tree, |_| Bot::new(true), seed);
+        let selected = assert_matches_brute(
+            &tree,
+            &tree,
+            |_| Bot::new(true).with_tie_break(TieBreak::Predicate(|_, &action, _| action == 2)),
+            seed,
+        );
+        for (first, selected) in first.iter().zip(&selected) {
+            if first != selected {
+                assert_eq!(*selected, Some(2), "seed: {}", seed);
+                changed += 1;
+            }
+        }
+    }
+    assert!(changed > 0);
+}
+
+#[test]
+fn tie_break_random() {
+    use crate::alpha_beta::TieBreak;
+
+    let mut changed = 0;
+    for seed in 0..300 {
+        let tree = tied_tree(seed);
+        let random = || Bot::new(true).with_tie_break(TieBreak::Random(u64::from(seed)));
+        let first = assert_matches_brute(&tree, &tree, |_| Bot::new(true), seed);
+        let selected = assert_matches_brute(&tree, &tree, |_| random(), seed);
+        let repeated = assert_matches_brute(&tree, &tree, |_| random(), seed);
+        assert_eq!(selected, repeated, "seed: {}", seed);
+        changed += first.iter().zip(&selected).filter(|(a, b)| a != b).count();
+    }
+    assert!(changed > 0);
+}
+
+/// Sleeps before returning the possible actions of states which are at least three actions below the root,
+/// so short time limits stop the search at this depth.
+#[derive(Clone)]
+struct Slow(Node, u32);
+
+impl Game for Slow {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = <Node as Game>::Actions;
+
+    fn actions(&self, player: bool) -> (bool, Self::Actions) {
+        if self.1 >= 3 {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        self.0.actions(player)
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i8 {
+        self.1 += 1;
+        self.0.execute(action, player)
+    }
+}
+
+/// The tie break still works if the time limit stopped the search.
+#[test]
+fn tie_break_time_limit() {
+    use crate::alpha_beta::TieBreak;
+    use std::time::Duration;
+
+    let line = || {
+        Node::new(false, 5)
+            .with_children(&[Node::new(true, 5).with_children(&[Node::new(false, 5)
+                .with_children(&[Node::new(true, 5).with_children(&[Node::new(false, 5)])])])])
+    };
+    let tree = Slow(Node::root().with_children(&[line(), line(), line()]), 0);
+
+    let mut selected = [false; 3];
+    for seed in 0..20 {
+        let action = Bot::new(true)
+            .with_tie_break(TieBreak::Random(seed))
+            .detailed_select(&tree, Duration::from_millis(10))
+            .unwrap();
+        assert!(!action.terminal);
+        assert!(action.depth.is_some());
+        selected[action.path[0]] = true;
+    }
+    assert_eq!(selected, [true; 3]);
+}
+
+#[test]
+fn fitness_blur() {
+    use crate::alpha_beta::TieBreak;
+
+    let mut blurred = 0;
+    for seed in 0..300 {
+        let tree = random_tree(seed);
+        let mut brute = Brute::new(true);
+        for &depth in DEPTHS.iter() {
+            let selected = Bot::new(true)
+                .with_fitness_blur(3)
+                .with_tie_break(TieBreak::Random(seed as u64))
+                .select(&tree, Depth(depth));
+            let selected = match selected {
+                Some(selected) if tree.child_count() > 1 => selected,
+                _ => continue,
+            };
+            let best = (0..tree.child_count())
+                .map(|action| brute.minimax(&tree, &action, depth))
+                .max()
+                .unwrap();
+            let fitness = brute.minimax(&tree, &selected, depth);
+            assert!(
+                i16::from(fitness) >= i16::from(best) - 3,
+                "seed: {}, depth: {}",
+                seed,
+                depth
+            );
+            if fitness < best {
+                blurred += 1;
+            }
+        }
+    }
+    assert!(blurred > 0);
+}
+
+#[test]
+fn is_best() {
+    for seed in 0..200 {
+        let tree = tied_tree(seed);
+        let mut brute = Brute::new(true);
+        let mut bot = Bot::new(true);
+        for action in 0..=tree.child_count() {
+            assert_eq!(
+                bot.is_best(&tree, &action, ToCompletion),
+                action < tree.child_count() && brute.check_if_best(&tree, Some(&action), u32::MAX),
+                "seed: {}, action: {}",
+                seed,
+                action
+            );
+        }
+    }
+}
+
+#[test]
+fn root_beam_width() {
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        let mut brute = Brute::new(true);
+        for &depth in DEPTHS.iter() {
+            let expected = Bot::new(true).select(&tree, Depth(depth));
+            let wide = Bot::new(true)
+                .with_root_beam_width(tree.child_count().max(1))
+                .select(&tree, Depth(depth));
+            assert_eq!(wide, expected, "seed: {}, depth: {}", seed, depth);
+
+            // The selected action may be worse, but its fitness is still correct.
+            if let Some(action) = Bot::new(true)
+                .with_root_beam_width(1)
+                .detailed_select(&tree, Depth(depth))
+            {
+                assert_eq!(
+                    action.fitness,
+                    brute.minimax(&tree, &action.path[0], depth),
+                    "seed: {}, depth: {}",
+                    seed,
+                    depth
+                );
+            }
+        }
+    }
+}
+
+/// Returns every action twice.
+#[derive(Clone)]
+struct Duplicated(Node);
+
+impl Game for Duplicated {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = Vec<usize>;
+
+    fn actions(&self, player: bool) -> (bool, Vec<usize>) {
+        let (active, actions) = self.0.actions(player);
+        (active, actions.flat_map(|a| vec![a, a]).collect())
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i8 {
+        self.0.execute(action, player)
+    }
+}
+
+#[test]
+fn dedup_actions() {
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        for &depth in DEPTHS.iter() {
+            let expected = Bot::new(true).select(&tree, Depth(depth));
+            let mut bot = Bot::new(true).with_dedup_actions(true);
+            let mut logger = Logger::new(Depth(depth));
+            let selected = bot.select(&Duplicated(tree.clone()), &mut logger);
+            assert_eq!(selected, expected, "seed: {}, depth: {}", seed, depth);
+            assert_eq!(bot.last_root_actions().len(), tree.child_count());
+
+            let mut duplicated = Logger::new(Depth(depth));
+            Bot::new(true).select(&Duplicated(tree.clone()), &mut duplicated);
+            assert!(logger.steps() <= duplicated.steps());
+        }
+    }
+}
+
+/// Replaces every fitness in `-2..=2` with `-5`, which is what contempt does with a draw value of `0`.
+#[derive(Clone)]
+struct Drawish(Node);
+
+impl Game for Drawish {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = <Node as Game>::Actions;
+
+    fn actions(&self, player: bool) -> (bool, Self::Actions) {
+        self.0.actions(player)
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i8 {
+        match self.0.execute(action, player) {
+            -2..=2 => -5,
+            fitness => fitness,
+        }
+    }
+}
+
+#[test]
+fn contempt() {
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        let drawish = Drawish(tree.clone());
+        assert_matches_brute(
+            &tree,
+            &drawish,
+            |_| Bot::new(true).with_contempt(5).with_draw_value(0, 2),
+            seed,
+        );
+    }
+}
+
+/// Only returns the first two actions below the root, sorted like the bot sorts them.
+#[derive(Clone)]
+struct Limited(Node, bool);
+
+impl Game for Limited {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = Vec<usize>;
+
+    fn actions(&self, player: bool) -> (bool, Vec<usize>) {
+        let (active, actions) = self.0.actions(player);
+        let mut actions: Vec<_> = actions
+            .map(|action| (action, self.0.clone().execute(&action, player)))
+            .collect();
+        if !self.1 {
+            if active {
+                actions.sort_by(|(_, a), (_, b)| b.cmp(a));
+            } else {
+                actions.sort_by_key(|&(_, fitness)| fitness);
+            }
+            actions.truncate(2);
+        }
+        (
+            active,
+            actions.into_iter().map(|(action, _)| action).collect(),
+        )
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i8 {
+        self.1 = false;
+        self.0.execute(action, player)
+    }
+}
+
+#[test]
+fn max_children() {
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        let limited = Limited(tree.clone(), true);
+        assert_matches_brute(
+            &tree,
+            &limited,
+            |_| Bot::new(true).with_max_children(2),
+            seed,
+        );
+    }
+}
+
+#[test]
+fn last_pruned_actions() {
+    use crate::alpha_beta::Pruned;
+
+    let (mut bound, mut beam, mut cancelled) = (0, 0, 0);
+    for seed in 0..200 {
+        let tree = random_tree(seed);
+        let mut brute = Brute::new(true);
+        for &depth in DEPTHS.iter() {
+            let mut bot = Bot::new(true);
+            let selected = bot.select(&tree, Depth(depth));
+            for &(action, reason) in bot.last_pruned_actions() {
+                assert_ne!(Some(action), selected, "seed: {}, depth: {}", seed, depth);
+                assert_eq!(reason, Pruned::Bound);
+                bound += 1;
+                assert!(
+                    brute.minimax(&tree, &action, depth)
+                        <= brute.minimax(&tree, &selected.unwrap(), depth)
+                );
+            }
+        }
+
+        let mut bot = Bot::new(true).with_root_beam_width(1);
+        bot.select(&tree, ToCompletion);
+        beam += bot
+            .last_pruned_actions()
+            .iter()
+            .filter(|(_, reason)| *reason == Pruned::Beam)
+            .count();
+
+        for steps in 0..20 {
+            let mut bot = Bot::new(true);
+            let selected = bot.select(&tree, Steps(steps));
+            let pruned = bot.last_pruned_actions();
+            assert!(pruned.iter().all(|&(action, _)| Some(action) != selected));
+            assert!(pruned.windows(2).all(|w| w[0].0 < w[1].0));
+            cancelled += pruned
+                .iter()
+                .filter(|(_, reason)| *reason == Pruned::Cancelled)
+                .count();
+        }
+    }
+    assert!(bound > 0);
+    assert!(beam > 0);
+    assert!(cancelled > 0);
+}
+
+/// Remembered results must not change the fitness, even if they are reused between searches.
+#[test]
+fn transposition_table() {
+    let mut fewer_steps = 0;
+    for seed in 0..300 {
+        let tree = if seed % 2 == 0 {
+            random_tree(seed)
+        } else {
+            tied_tree(seed)
+        };
+        let mut brute = Brute::new(true);
+        for &max_entries in [3, 1 << 16].iter() {
+            let mut bot = Bot::new(true).with_transposition_table(max_entries);
+            for &depth in [0, 1, 2, 3, u32::MAX, 2].iter() {
+                let mut logger = Logger::new(Depth(depth));
+                Bot::new(true).select(&tree, &mut logger);
+                let mut remembered = Logger::new(Depth(depth));
+                Bot::new(true)
+                    .with_transposition_table(max_entries)
+                    .select(&tree, &mut remembered);
+                if remembered.steps() < logger.steps() {
+                    fewer_steps += 1;
+                }
+
+                let selected = match assert_best(&mut brute, &mut bot, &tree, &tree, depth, seed) {
+                    Some(selected) => selected,
+                    None => continue,
+                };
+
+                let mut node = tree.clone();
+                for action in selected.path.iter() {
+                    node.execute(action, true);
+                }
+                assert!(!selected.terminal || node.is_leaf(), "seed: {}", seed);
+            }
+        }
+    }
+    assert!(fewer_steps > 0);
+}
+
+/// Changing the settings of the bot must forget the remembered results.
+#[test]
+fn transposition_table_settings() {
+    for seed in 0..200 {
+        // Every fitness of a tied tree is changed by the contempt.
+        let tied = tied_tree(seed);
+        let drawish = Drawish(tied.clone());
+        let tree = random_tree(seed);
+        let limited = Limited(tree.clone(), true);
+        for &depth in DEPTHS.iter() {
+            let mut bot = Bot::new(true).with_transposition_table(1 << 16);
+            bot.select(&tied, Depth(depth));
+            let mut bot = bot.with_contempt(5).with_draw_value(0, 2);
+            assert_best(
+                &mut Brute::new(true),
+                &mut bot,
+                &tied,
+                &drawish,
+                depth,
+                seed,
+            );
+
+            let mut bot = Bot::new(true).with_transposition_table(1 << 16);
+            bot.select(&tree, Depth(depth));
+            let mut bot = bot.with_max_children(2);
+            assert_best(
+                &mut Brute::new(true),
+                &mut bot,
+                &tree,
+                &limited,
+                depth,
+                seed,
+            );
+        }
+    }
+}
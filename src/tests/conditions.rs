@@ -0,0 +1,313 @@
+//! Tests for the interaction between the bot and its run conditions.
+use super::*;
+use crate::brute::Brute;
+use crate::{
+    FitnessCondition, InnerSkipUnfinishable, IntoFitnessCondition, MinDepth, RunCondition,
+    SkipUnfinishable, StopWhen,
+};
+use std::time::{Duration, Instant};
+
+/// Records every call to `depth`.
+struct Recorder(Vec<(u32, i8)>);
+
+impl FitnessCondition<i8> for &mut Recorder {
+    fn step(&mut self) -> bool {
+        true
+    }
+
+    fn depth(&mut self, depth: u32, fitness: i8) -> bool {
+        self.0.push((depth, fitness));
+        true
+    }
+}
+
+impl IntoFitnessCondition<i8> for &mut Recorder {
+    type FitnessCondition = Self;
+
+    fn into_fitness_condition(self) -> Self {
+        self
+    }
+}
+
+fn deep_tree() -> Node {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 4).with_children(&[
+                Node::new(false, 1),
+            ]),
+            Node::new(true, 2),
+        ]),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8).with_children(&[
+                Node::new(false, 6),
+            ]),
+            Node::new(true, 9),
+        ]),
+    ]);
+    tree
+}
+
+#[test]
+fn stop_when_fitness_reached() {
+    let tree = deep_tree();
+
+    let mut logger = Logger::new(ToCompletion);
+    let condition = StopWhen::new(&mut logger, |depth, fitness| depth > 0 && fitness >= 8);
+    assert_eq!(Bot::new(true).select(&tree, condition), Some(1));
+    assert_eq!(logger.depth(), 1);
+    assert!(!logger.completed());
+
+    // The predicate is never true, so this is the same as `ToCompletion`.
+    let mut logger = Logger::new(ToCompletion);
+    let condition = StopWhen::new(&mut logger, |_, fitness| fitness > 9);
+    assert_eq!(Bot::new(true).select(&tree, condition), Some(1));
+    assert!(logger.completed());
+}
+
+#[test]
+fn fitness_for_each_depth() {
+    let tree = deep_tree();
+
+    let mut recorder = Recorder(Vec::new());
+    assert_eq!(Bot::new(true).select(&tree, &mut recorder), Some(1));
+    assert_eq!(recorder.0, &[(0, 7), (1, 8), (2, 6), (3, 6)]);
+}
+
+/// Wrapping a `RunCondition` in a `StopWhen` which never stops must not change the result.
+#[test]
+fn run_condition_unchanged() {
+    for seed in 0..200u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..12).map(|i| (seed.wrapping_mul(31) ^ i) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let completed = Bot::new(true).select(&tree, ToCompletion);
+        assert!(Brute::new(true).check_if_best(&tree, completed.as_ref(), u32::MAX));
+        let wrapped = Bot::new(true).select(&tree, StopWhen::new(ToCompletion, |_, _: i8| false));
+        assert_eq!(completed, wrapped);
+
+        for depth in 0..4 {
+            let selected = Bot::new(true).select(&tree, Depth(depth));
+            assert!(Brute::new(true).check_if_best(&tree, selected.as_ref(), depth));
+            let wrapped =
+                Bot::new(true).select(&tree, StopWhen::new(Depth(depth), |_, _: i8| false));
+            assert_eq!(selected, wrapped);
+        }
+    }
+}
+
+#[test]
+fn min_depth() {
+    let tree = deep_tree();
+
+    for depth in 0..4 {
+        let expected = Bot::new(true).select(&tree, Depth(depth));
+        let selected = Bot::new(true).select(&tree, MinDepth::new(depth, Steps(0)));
+        assert_eq!(expected, selected, "depth: {}", depth);
+    }
+
+    // The tree is not deep enough, so the search still terminates.
+    let mut logger = Logger::new(MinDepth::new(100, Steps(0)));
+    assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(1));
+    assert!(logger.depth() < 100);
+}
+
+#[test]
+fn check_interval() {
+    for seed in 0..200u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..12).map(|i| (seed.wrapping_mul(17) ^ i) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut every = Logger::new(ToCompletion);
+        let expected = Bot::new(true).select(&tree, &mut every);
+        let mut sparse = Logger::new(ToCompletion);
+        let selected = Bot::new(true)
+            .with_check_interval(4)
+            .select(&tree, &mut sparse);
+        assert_eq!(expected, selected);
+        assert_eq!(every.depth(), sparse.depth());
+        assert!(sparse.steps() <= (every.steps() + 3) / 4);
+    }
+}
+
+/// A game which never ends.
+#[derive(Clone)]
+struct Endless(u8);
+
+impl crate::Game for Endless {
+    type Player = ();
+    type Action = u8;
+    type Fitness = u8;
+    type Actions = std::ops::Range<u8>;
+
+    fn actions(&self, _: ()) -> (bool, Self::Actions) {
+        (true, 0..3)
+    }
+
+    fn execute(&mut self, action: &u8, _: ()) -> u8 {
+        self.0 = self.0.wrapping_add(*action);
+        self.0
+    }
+}
+
+#[test]
+fn cancel_and_restart() {
+    use crate::Cancellable;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    let flag = Arc::new(AtomicBool::new(false));
+    let canceller = {
+        let flag = flag.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            flag.store(true, Ordering::Relaxed);
+        })
+    };
+
+    let mut bot = Bot::new(());
+    let mut logger = Logger::new(Cancellable::new(flag.clone(), ToCompletion));
+    assert!(bot.select(&Endless(0), &mut logger).is_some());
+    assert!(!logger.completed());
+    canceller.join().unwrap();
+
+    // The cancelled search does not influence the next one.
+    flag.store(false, Ordering::Relaxed);
+    let condition = Cancellable::new(flag, Depth(4));
+    assert_eq!(
+        bot.detailed_select(&Endless(5), condition)
+            .map(|act| act.path),
+        Bot::new(())
+            .detailed_select(&Endless(5), Depth(4))
+            .map(|act| act.path)
+    );
+}
+
+#[test]
+fn best_move_history() {
+    let tree = deep_tree();
+    for depth in 0..4 {
+        let mut bot = Bot::new(true);
+        let selected = bot.select(&tree, Depth(depth));
+        let depths: Vec<_> = bot.best_move_history().iter().map(|&(d, _)| d).collect();
+        assert_eq!(depths, (0..depth).collect::<Vec<_>>());
+        if depth > 0 {
+            assert_eq!(bot.best_move_history().last().map(|&(_, a)| a), selected);
+        }
+    }
+}
+
+#[test]
+fn shared_condition() {
+    use crate::SharedCondition;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    /// Counts the steps of all searches.
+    struct Counter(AtomicU32);
+
+    impl SharedCondition for Counter {
+        fn step(&self) -> bool {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            true
+        }
+
+        fn depth(&self, _: u32) -> bool {
+            true
+        }
+    }
+
+    let mut logger = Logger::new(ToCompletion);
+    let expected = Bot::new(true).select(&deep_tree(), &mut logger);
+
+    let counter = Arc::new(Counter(AtomicU32::new(0)));
+    let searches: Vec<_> = (0..4)
+        .map(|_| {
+            let counter = counter.clone();
+            thread::spawn(move || Bot::new(true).select(&deep_tree(), counter))
+        })
+        .collect();
+    for search in searches {
+        assert_eq!(search.join().unwrap(), expected);
+    }
+    assert_eq!(
+        u64::from(counter.0.load(Ordering::Relaxed)),
+        4 * logger.steps()
+    );
+}
+
+#[test]
+fn on_node() {
+    use crate::OnNode;
+
+    let tree = deep_tree();
+    for depth in 1..5 {
+        let mut nodes = Vec::new();
+        let condition = OnNode::new(Depth(depth), |depth, _, _| nodes.push(depth));
+        let selected = Bot::new(true).select(&tree, condition);
+        assert_eq!(selected, Bot::new(true).select(&tree, Depth(depth)));
+        assert!(!nodes.is_empty());
+        assert!(nodes.iter().all(|&d| d < depth), "depth: {}", depth);
+    }
+}
+
+#[test]
+fn skip_unfinishable_estimate() {
+    let ms = Duration::from_millis;
+
+    let now = Instant::now();
+    let mut condition = InnerSkipUnfinishable {
+        end: now + ms(10_000),
+        depth_start: now,
+        last: None,
+        previous: None,
+    };
+    assert_eq!(condition.estimate(), ms(0));
+    condition.last = Some(ms(20));
+    assert_eq!(condition.estimate(), ms(20));
+    // Each depth is expected to grow by the same factor as the last one.
+    condition.previous = Some(ms(10));
+    assert_eq!(condition.estimate(), ms(40));
+}
+
+#[test]
+fn skip_unfinishable_depth() {
+    let ms = Duration::from_millis;
+
+    // The last depth took at least 100ms, which is 10 times longer than the one before,
+    // so the next one is expected to take at least a second.
+    let condition = |remaining| {
+        let now = Instant::now();
+        InnerSkipUnfinishable {
+            end: now + remaining,
+            depth_start: now - ms(100),
+            last: Some(ms(10)),
+            previous: None,
+        }
+    };
+
+    let mut skipped = condition(ms(500));
+    assert!(!RunCondition::depth(&mut skipped, 2));
+    // Steps are still allowed, the current depth is only skipped.
+    assert!(RunCondition::step(&mut skipped));
+
+    let mut started = condition(ms(60_000));
+    assert!(RunCondition::depth(&mut started, 2));
+    assert!(started.last >= Some(ms(100)));
+    assert_eq!(started.previous, Some(ms(10)));
+}
+
+#[test]
+fn skip_unfinishable_result() {
+    let tree = deep_tree();
+    let mut logger = Logger::new(SkipUnfinishable(Duration::from_secs(60)));
+    let selected = Bot::new(true).select(&tree, &mut logger);
+    // Such a small tree is searched completely in time.
+    assert!(logger.completed());
+    assert!(Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX));
+}
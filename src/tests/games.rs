@@ -0,0 +1,258 @@
+//! Tests using the reference games, validating the bot against brute force.
+use crate::brute::Brute;
+use crate::games::{ConnectFour, Fitness, Nim, Player, TicTacToe};
+use crate::play::Outcome;
+use crate::{Bot, Depth, Game, ToCompletion};
+
+/// Plays `game` until it is over, making sure each action of both players is perfect up to `depth`.
+fn play<T: Game<Player = Player>>(mut game: T, condition: Depth, depth: u32) -> T {
+    let mut first = Bot::new(Player::First);
+    let mut second = Bot::new(Player::Second);
+    loop {
+        let (player, action) = match (
+            first.select(&game, condition),
+            second.select(&game, condition),
+        ) {
+            (Some(action), None) => (Player::First, action),
+            (None, Some(action)) => (Player::Second, action),
+            (None, None) => return game,
+            (Some(_), Some(_)) => unreachable!("both players are active"),
+        };
+        assert!(Brute::new(player).check_if_best(&game, Some(&action), depth));
+        game.execute(&action, player);
+    }
+}
+
+#[test]
+fn nim() {
+    for flags in 0..14 {
+        let game = play(Nim::new(flags, 3), Depth(u32::MAX), u32::MAX);
+        assert_eq!(game.flags(), 0);
+        let expected = if flags % 4 == 0 {
+            Player::Second
+        } else {
+            Player::First
+        };
+        if flags != 0 {
+            assert_eq!(
+                Outcome::of(&game),
+                Outcome::Win(expected),
+                "flags: {}",
+                flags
+            );
+        }
+    }
+}
+
+#[test]
+fn tic_tac_toe() {
+    let mut game = TicTacToe::new();
+    game.place(0);
+    game.place(4);
+    let game = play(game, Depth(u32::MAX), u32::MAX);
+    assert!(game.is_finished());
+    // Perfect play always ends in a draw.
+    assert_eq!(Outcome::of(&game), Outcome::Draw);
+
+    let mut game = TicTacToe::new();
+    game.place(4);
+    game.place(1);
+    let mut bot = Bot::new(Player::First);
+    let action = bot.detailed_select(&game, ToCompletion).unwrap();
+    assert_eq!(action.fitness, Fitness::Win);
+    assert!(action.terminal);
+}
+
+#[test]
+fn connect_four() {
+    let game = play(ConnectFour::new(), Depth(3), 3);
+    assert!(game.is_finished());
+
+    // Both players have three pieces in a row, so the first player wins immediately.
+    let mut game = ConnectFour::new();
+    for &column in [0, 0, 1, 1, 2, 2].iter() {
+        game.drop_piece(column);
+    }
+    let mut bot = Bot::new(Player::First);
+    assert_eq!(bot.select(&game, Depth(2)), Some(3));
+    game.drop_piece(6);
+    // The second player has to block the first one.
+    let mut bot = Bot::new(Player::Second);
+    assert_eq!(bot.select(&game, Depth(2)), Some(3));
+}
+
+/// Validates the bot against brute force on positions which are too large to be searched completely.
+#[test]
+fn connect_four_depth_five() {
+    for opening in [
+        &[][..],
+        &[3, 3],
+        &[0, 0, 1, 1],
+        &[1, 1, 2, 2, 6],
+        &[3, 2, 3, 2, 4],
+    ]
+    .iter()
+    {
+        let mut game = ConnectFour::new();
+        for &column in opening.iter() {
+            game.drop_piece(column);
+        }
+        let player = game.active();
+        let action = Bot::new(player).select(&game, Depth(5));
+        assert!(
+            Brute::new(player).check_if_best(&game, action.as_ref(), 5),
+            "opening: {:?}",
+            opening
+        );
+    }
+}
+
+#[test]
+fn self_play() {
+    use crate::play;
+
+    for flags in 1..14 {
+        let mut first = Bot::new(Player::First);
+        let mut second = Bot::new(Player::Second);
+        let (actions, outcome) =
+            play::self_play(Nim::new(flags, 3), &mut first, &mut second, ToCompletion);
+        assert_eq!(actions.iter().sum::<u32>(), flags);
+        let expected = if flags % 4 == 0 {
+            Player::Second
+        } else {
+            Player::First
+        };
+        assert_eq!(outcome, Outcome::Win(expected), "flags: {}", flags);
+    }
+
+    let mut first = Bot::new(Player::First);
+    let mut second = Bot::new(Player::Second);
+    let (actions, outcome) =
+        play::self_play(TicTacToe::new(), &mut first, &mut second, ToCompletion);
+    assert_eq!(actions.len(), 9);
+    assert_eq!(outcome, Outcome::Draw);
+}
+
+#[test]
+fn solve() {
+    use crate::alpha_beta::GameValue;
+
+    for flags in 1..14 {
+        let nim = Nim::new(flags, 3);
+        let first = Bot::new(Player::First).solve(&nim).unwrap();
+        let second = Bot::new(Player::Second).solve(&nim).unwrap();
+        match (first, second) {
+            (GameValue::ProvenWin { distance }, GameValue::ProvenLoss { distance: d })
+            | (GameValue::ProvenLoss { distance }, GameValue::ProvenWin { distance: d }) => {
+                assert!(distance > 0 && d > 0);
+                // The player who removes the last flag wins.
+                let first_wins = matches!(first, GameValue::ProvenWin { .. });
+                assert_eq!(first_wins, flags % 4 != 0, "flags: {}", flags);
+                assert_eq!(distance % 2 == 1, first_wins, "flags: {}", flags);
+                assert_eq!(d % 2 == 1, first_wins, "flags: {}", flags);
+            }
+            values => panic!("flags: {}, values: {:?}", flags, values),
+        }
+    }
+
+    let value = Bot::new(Player::Second).solve(&TicTacToe::new());
+    assert_eq!(value, Some(GameValue::ProvenDraw { distance: 9 }));
+}
+
+#[test]
+fn game_over() {
+    use crate::alpha_beta::GameValue;
+
+    let mut game = TicTacToe::new();
+    let first = Bot::new(Player::First);
+    let second = Bot::new(Player::Second);
+    for &field in [0, 3, 1, 4].iter() {
+        assert_eq!(first.game_over(&game), None);
+        assert_eq!(second.game_over(&game), None);
+        game.place(field);
+    }
+    game.place(2);
+    assert_eq!(
+        first.game_over(&game),
+        Some(GameValue::ProvenWin { distance: 0 })
+    );
+    assert_eq!(
+        second.game_over(&game),
+        Some(GameValue::ProvenLoss { distance: 0 })
+    );
+
+    let game = play(TicTacToe::new(), Depth(u32::MAX), u32::MAX);
+    assert_eq!(
+        first.game_over(&game),
+        Some(GameValue::ProvenDraw { distance: 0 })
+    );
+}
+
+/// Tic-tac-toe and connect four reach the same states using different orders of actions.
+#[test]
+fn transposition_table() {
+    let mut game = TicTacToe::new();
+    let mut first = Bot::new(Player::First).with_transposition_table(1 << 16);
+    let mut second = Bot::new(Player::Second).with_transposition_table(1 << 16);
+    while !game.is_finished() {
+        let player = game.active();
+        let bot = if player == Player::First {
+            &mut first
+        } else {
+            &mut second
+        };
+        let action = bot.select(&game, ToCompletion);
+        assert!(Brute::new(player).check_if_best(&game, action.as_ref(), u32::MAX));
+        game.place(action.unwrap());
+    }
+    assert_eq!(Outcome::of(&game), Outcome::Draw);
+
+    let mut bots = [
+        Bot::new(Player::First).with_transposition_table(1 << 16),
+        Bot::new(Player::Second).with_transposition_table(1 << 16),
+    ];
+    for opening in [&[][..], &[3, 3], &[0, 0, 1, 1], &[3, 2, 3, 2, 4]].iter() {
+        let mut game = ConnectFour::new();
+        for &column in opening.iter() {
+            game.drop_piece(column);
+        }
+        let player = game.active();
+        let bot = &mut bots[(player == Player::Second) as usize];
+        let action = bot.select(&game, Depth(5));
+        assert!(
+            Brute::new(player).check_if_best(&game, action.as_ref(), 5),
+            "opening: {:?}",
+            opening
+        );
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn select_parallel() {
+    use std::time::{Duration, Instant};
+
+    for opening in [&[][..], &[3, 3], &[0, 0, 1, 1], &[1, 1, 2, 2, 6]].iter() {
+        let mut game = ConnectFour::new();
+        for &column in opening.iter() {
+            game.drop_piece(column);
+        }
+        let player = game.active();
+        for &threads in [2, 4, 7].iter() {
+            let action = Bot::new(player).select_parallel(&game, Depth(5), threads);
+            assert!(
+                Brute::new(player).check_if_best(&game, action.as_ref(), 5),
+                "opening: {:?}, threads: {}",
+                opening,
+                threads
+            );
+        }
+    }
+
+    // The deadline stops all threads, even though connect four is too large to be searched completely.
+    let start = Instant::now();
+    let action =
+        Bot::new(Player::First).select_parallel(&ConnectFour::new(), Duration::from_millis(100), 4);
+    assert!(action.is_some());
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
@@ -2,6 +2,9 @@ use crate::{tree::Node, Bot, Depth, Logger, Steps, ToCompletion};
 
 mod brute;
 mod completed;
+mod conditions;
+#[cfg(feature = "games")]
+mod games;
 mod partial;
 
 #[test]
@@ -40,3 +43,427 @@ fn logger_eq() {
     Bot::new(true).select(&logger_eq, &mut logger);
     assert_eq!(logger.depth(), 2);
 }
+
+#[test]
+fn evaluate_terminal() {
+    assert_eq!(
+        Bot::new(true).evaluate(&Node::new(true, 3), ToCompletion),
+        Some(3)
+    );
+    assert_eq!(
+        Bot::new(true).evaluate(&Node::new(false, -2), Depth(0)),
+        Some(-2)
+    );
+}
+
+#[test]
+fn evaluate_inactive() {
+    #[rustfmt::skip]
+    let tree = Node::new(false, 0).with_children(&[
+        Node::new(true, 3).with_children(&[
+            Node::new(false, 1),
+            Node::new(false, 6),
+        ]),
+        Node::new(false, 8).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 9),
+        ]),
+        Node::new(true, 7),
+    ]);
+
+    // The opponent chooses the action leading to `4`: `6` after the first action
+    // and `7` after the last one are both better for the bot.
+    assert_eq!(Bot::new(true).evaluate(&tree, ToCompletion), Some(4));
+    // The bot only has to look one action ahead, so `Depth(0)` is enough.
+    assert_eq!(Bot::new(true).evaluate(&tree, Depth(0)), Some(4));
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), None);
+}
+
+#[test]
+fn forced_action() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 3).with_children(&[
+            Node::new(true, 1),
+            Node::new(true, 8),
+        ]),
+    ]);
+
+    let mut logger = Logger::new(ToCompletion);
+    let action = Bot::new(true).detailed_select(&tree, &mut logger).unwrap();
+    assert_eq!(action.path, &[0]);
+    assert_eq!(action.fitness, 3);
+    assert_eq!(logger.steps(), 0);
+
+    // `evaluate` still searches the only action.
+    assert_eq!(Bot::new(true).evaluate(&tree, ToCompletion), Some(1));
+}
+
+/// An action with a proven fitness is preferred over an equally good estimate.
+#[test]
+fn prefer_terminated() {
+    let open = || {
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 5).with_children(&[Node::new(false, 9)]),
+            Node::new(true, 6).with_children(&[Node::new(false, 5)]),
+        ])
+    };
+
+    for depth in 1..3 {
+        let tree = Node::root().with_children(&[open(), Node::new(false, 5)]);
+        let action = Bot::new(true).detailed_select(&tree, Depth(depth)).unwrap();
+        assert_eq!(action.path, &[1], "depth: {}", depth);
+        assert!(action.terminal);
+
+        let tree = Node::root().with_children(&[Node::new(false, 5), open()]);
+        let action = Bot::new(true).detailed_select(&tree, Depth(depth)).unwrap();
+        assert_eq!(action.path, &[0], "depth: {}", depth);
+        assert!(action.terminal);
+    }
+}
+
+#[test]
+fn opponent_model() {
+    use crate::alpha_beta::OpponentModel;
+
+    #[rustfmt::skip]
+    let tree = Node::new(false, 0).with_children(&[
+        Node::new(true, 6),
+        Node::new(true, 9).with_children(&[
+            Node::new(false, -10),
+        ]),
+        Node::new(true, 7).with_children(&[
+            Node::new(false, 8),
+            Node::new(false, 5),
+        ]),
+    ]);
+
+    assert_eq!(Bot::new(true).evaluate(&tree, ToCompletion), Some(-10));
+    for &(k, expected) in [(1, 6), (2, 6), (3, -10), (4, -10)].iter() {
+        let mut bot = Bot::new(true).with_opponent_model(OpponentModel::TopK(k));
+        assert_eq!(
+            bot.evaluate(&tree, ToCompletion),
+            Some(expected),
+            "k: {}",
+            k
+        );
+        let root = Node::root().with_children(&[tree.clone(), Node::new(false, 7)]);
+        let action = bot.detailed_select(&root, ToCompletion).unwrap();
+        assert_eq!(action.fitness, std::cmp::max(expected, 7), "k: {}", k);
+    }
+}
+
+#[test]
+fn shortest_path_to() {
+    use crate::path;
+
+    let goal = Node::new(true, 9);
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 1).with_children(&[
+            Node::new(true, 2).with_children(&[
+                Node::new(true, 9),
+            ]),
+        ]),
+        Node::new(false, 3).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 9),
+        ]),
+    ]);
+
+    assert_eq!(
+        path::shortest_path_to(&tree, true, |n| *n == goal, 5),
+        Some(vec![1, 1])
+    );
+    assert_eq!(path::shortest_path_to(&tree, true, |n| *n == goal, 1), None);
+    assert_eq!(
+        path::shortest_path_to(&tree, true, |n| *n == tree, 0),
+        Some(vec![])
+    );
+    assert_eq!(
+        path::shortest_path_to(&tree, true, |n| n.child_count() == 3, 5),
+        None
+    );
+}
+
+/// The active player is decided by `Game::actions`, so a player may move multiple times in a row.
+#[test]
+fn extra_turns() {
+    use crate::Game;
+
+    // The bot moves twice after the first action.
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(true, 0).with_children(&[
+            Node::new(true, 1),
+            Node::new(true, 9),
+        ]),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 6),
+        ]),
+    ]);
+
+    let action = Bot::new(true).detailed_select(&tree, ToCompletion).unwrap();
+    assert_eq!(action.path, &[0, 1]);
+    assert_eq!(action.fitness, 9);
+    assert_eq!(Bot::new(true).select(&tree, Depth(2)), Some(0));
+
+    // The opponent moves twice after the first action.
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 0).with_children(&[
+            Node::new(false, 9).with_children(&[
+                Node::new(true, 9),
+                Node::new(true, 2),
+            ]),
+        ]),
+        Node::new(false, 4),
+    ]);
+
+    let action = Bot::new(true).detailed_select(&tree, ToCompletion).unwrap();
+    assert_eq!(action.path, &[1]);
+    assert_eq!(action.fitness, 4);
+    assert_eq!(Bot::new(true).select(&tree, Depth(3)), Some(1));
+    // The opponent is still to move, so the bot does not select an action.
+    let mut next = tree.clone();
+    next.execute(&0, true);
+    assert_eq!(Bot::new(true).select(&next, ToCompletion), None);
+    assert_eq!(Bot::new(true).evaluate(&next, ToCompletion), Some(2));
+}
+
+#[test]
+fn last_root_actions() {
+    let tree = Node::root().with_children(&[Node::new(false, 2), Node::new(false, 1)]);
+
+    let mut bot = Bot::new(true);
+    assert_eq!(bot.select(&tree, Depth(1)), Some(0));
+    assert_eq!(bot.last_root_actions(), &[0, 1]);
+
+    assert_eq!(bot.select(&Node::new(false, 0), Depth(1)), None);
+    assert!(bot.last_root_actions().is_empty());
+}
+
+/// Only the fifth action can win the game.
+#[derive(Clone)]
+struct LateWin {
+    moves: u8,
+    limit: u8,
+}
+
+impl crate::Game for LateWin {
+    type Player = ();
+    type Action = bool;
+    type Fitness = i8;
+    type Actions = Vec<bool>;
+
+    fn actions(&self, _: ()) -> (bool, Vec<bool>) {
+        if self.moves < 5 {
+            (true, vec![false, true])
+        } else {
+            (true, Vec::new())
+        }
+    }
+
+    fn execute(&mut self, action: &bool, _: ()) -> i8 {
+        self.moves += 1;
+        if self.moves == 5 && *action {
+            10
+        } else {
+            0
+        }
+    }
+
+    fn move_limit_reached(&self) -> bool {
+        self.moves >= self.limit
+    }
+
+    fn terminal_fitness(&self, _: ()) -> Option<i8> {
+        Some(0)
+    }
+}
+
+#[test]
+fn move_limit_reached() {
+    let unlimited = LateWin {
+        moves: 0,
+        limit: 10,
+    };
+    assert_eq!(Bot::new(()).evaluate(&unlimited, ToCompletion), Some(10));
+
+    let limited = LateWin { moves: 0, limit: 3 };
+    assert_eq!(Bot::new(()).evaluate(&limited, ToCompletion), Some(0));
+    assert_eq!(
+        Bot::new(()).evaluate(&LateWin { moves: 3, limit: 3 }, ToCompletion),
+        Some(0)
+    );
+    assert_eq!(
+        Bot::new(()).select(&LateWin { moves: 3, limit: 3 }, ToCompletion),
+        None
+    );
+}
+
+#[test]
+fn logger_reuse() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 4),
+        ]),
+        Node::new(false, 6),
+    ]);
+
+    let mut logger = Logger::new(Depth(1));
+    Bot::new(true).select(&tree, &mut logger);
+    assert!(!logger.completed());
+    let steps = logger.steps();
+
+    // `completed` only refers to the last search.
+    let mut logger = Logger::new(Depth(1)).accumulating();
+    Bot::new(true).select(&tree, &mut logger);
+    Bot::new(true).select(&Node::root(), &mut logger);
+    assert!(logger.completed());
+    assert_eq!(logger.steps(), steps);
+
+    logger.reset();
+    assert_eq!(logger.steps(), 0);
+    assert_eq!(logger.duration(), std::time::Duration::from_secs(0));
+}
+
+#[test]
+fn logger_accumulating_steps() {
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[Node::new(true, 4)]),
+        Node::new(false, 6),
+    ]);
+
+    let mut logger = Logger::new(ToCompletion).accumulating();
+    let mut previous = 0;
+    for _ in 0..5 {
+        Bot::new(true).select(&tree, &mut logger);
+        assert!(logger.steps() > previous);
+        previous = logger.steps();
+    }
+
+    // A whole game can take more steps than fit into an `u32`.
+    logger.steps = u64::from(u32::MAX);
+    Bot::new(true).select(&tree, &mut logger);
+    assert_eq!(logger.steps(), u64::from(u32::MAX) + previous / 5);
+}
+
+/// Removes up to `self.0` items, the more actions the better.
+///
+/// Panics when generating the actions of a finished game.
+#[derive(Clone)]
+struct Countdown(u8, u8);
+
+impl crate::Game for Countdown {
+    type Player = ();
+    type Action = u8;
+    type Fitness = u8;
+    type Actions = Vec<u8>;
+
+    fn actions(&self, _: ()) -> (bool, Vec<u8>) {
+        assert_ne!(self.0, 0, "actions of a terminal state");
+        (true, (1..=self.0).collect())
+    }
+
+    fn execute(&mut self, action: &u8, _: ()) -> u8 {
+        self.0 -= action;
+        self.1 += 1;
+        self.1
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+#[test]
+fn is_terminal() {
+    assert_eq!(Bot::new(()).select(&Countdown(3, 0), ToCompletion), Some(1));
+    assert_eq!(
+        Bot::new(()).evaluate(&Countdown(3, 0), ToCompletion),
+        Some(3)
+    );
+    assert_eq!(Bot::new(()).select(&Countdown(0, 0), ToCompletion), None);
+}
+
+/// `Depth(n)` looks exactly `n + 1` actions ahead.
+#[test]
+fn depth_plies() {
+    /// `len` actions by the bot, where only the last one has the given fitness.
+    fn chain(len: usize, fitness: i8) -> Node {
+        let mut node = Node::new(true, fitness);
+        for _ in 1..len {
+            node = Node::new(true, 1).with_children(&[node]);
+        }
+        node
+    }
+
+    for trap in 2..7 {
+        // The first action looks better, until the last action of its chain.
+        let tree = Node::root().with_children(&[
+            chain(trap, -10),
+            Node::new(true, 0).with_children(&[chain(trap, 0)]),
+        ]);
+        for depth in 0..9 {
+            let mut logger = Logger::new(Depth(depth));
+            let expected = if depth + 1 < trap as u32 { 0 } else { 1 };
+            assert_eq!(
+                Bot::new(true).select(&tree, &mut logger),
+                Some(expected),
+                "trap: {}, depth: {}",
+                trap,
+                depth
+            );
+            if depth + 1 < trap as u32 {
+                assert_eq!(logger.depth(), depth);
+            }
+        }
+    }
+}
+
+#[test]
+fn select_over_worlds() {
+    use crate::determinize::{self, Combine};
+
+    #[rustfmt::skip]
+    let worlds = [
+        Node::root().with_children(&[
+            Node::new(false, 8),
+            Node::new(false, 3),
+            Node::new(false, 4),
+        ]),
+        Node::root().with_children(&[
+            Node::new(false, 0),
+            Node::new(false, 3),
+            Node::new(false, 5).with_children(&[
+                Node::new(true, 2),
+            ]),
+        ]),
+    ];
+
+    let mut bot = Bot::new(true);
+    let mut select = |weights: &[f64], combine| {
+        determinize::select_over_worlds(&mut bot, &worlds, weights, combine, ToCompletion)
+    };
+    assert_eq!(select(&[1.0, 1.0], Combine::Average), Some(0));
+    assert_eq!(select(&[1.0, 3.0], Combine::Average), Some(1));
+    assert_eq!(select(&[1.0, 1.0], Combine::WorstCase), Some(1));
+    // Only actions which are possible in every world are considered.
+    assert_eq!(
+        determinize::select_over_worlds(
+            &mut bot,
+            &[worlds[0].clone(), Node::root()],
+            &[1.0, 1.0],
+            Combine::Average,
+            ToCompletion
+        ),
+        None
+    );
+    assert_eq!(
+        determinize::select_over_worlds(&mut bot, &[], &[], Combine::Average, ToCompletion),
+        None
+    );
+}
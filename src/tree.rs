@@ -26,7 +26,7 @@ use std::ops::Range;
 /// assert_eq!(bot.select(&tree, ToCompletion), Some(0));
 /// ```
 /// [game]: ../trait.Game.html
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Node {
     player: bool,
     // always from the perspective of the tested player
@@ -61,6 +61,10 @@ impl Game for Node {
     fn is_lower_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
         fitness == i8::MIN
     }
+
+    fn terminal_fitness(&self, _: Self::Player) -> Option<Self::Fitness> {
+        Some(self.fitness)
+    }
 }
 
 impl Node {
@@ -81,8 +85,8 @@ impl Node {
     /// Generates a tree from `bytes`, the total amount of tree nodes, excluding the root,
     /// is currently `bytes.len() - 4`.
     ///
-    /// The exact algorithm is not specified, so while the output is deterministic, it is not stable between versions
-    /// and changing it will not be a breaking change.
+    /// The exact algorithm is not specified. The output is deterministic and, as this is also used
+    /// to replay fuzzer artifacts, it only changes in breaking releases.
     pub fn from_bytes(bytes: &[u8]) -> Self {
         match bytes[0..4].try_into() {
             Ok(seed) => {
@@ -124,9 +128,9 @@ impl Node {
                     let mut next =
                         rng.next_u32() as usize % (pos.as_ref().unwrap().children.len() + 1);
                     while next != pos.as_ref().unwrap().children.len() {
-                        pos.take().map(|node| {
+                        if let Some(node) = pos.take() {
                             pos = Some(&mut node.children[next]);
-                        });
+                        }
                         next = rng.next_u32() as usize % (pos.as_ref().unwrap().children.len() + 1);
                     }
 
@@ -141,6 +145,49 @@ impl Node {
         }
     }
 
+    /// Generates a tree from the input format of the fuzz targets in the [`fuzz`][fuzz] folder,
+    /// returning `None` if `bytes` is shorter than 4 bytes, as these inputs are skipped by the fuzzer.
+    ///
+    /// This can be used to replay crash artifacts of the fuzzer,
+    /// the resulting tree is the same one the fuzz target was tested with.
+    ///
+    /// The tree generated for a given input is part of the public API: it only changes
+    /// in breaking releases, which mention the change in the changelog.
+    /// Artifacts saved with an older version of `rubot` therefore keep reproducing the same tree.
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, ToCompletion, brute::Brute, tree::Node};
+    /// // let bytes = std::fs::read("fuzz/artifacts/complete/crash-...").unwrap();
+    /// let bytes = [0xde, 0xad, 0xbe, 0xef, 3, 7, 2, 9];
+    /// let node = Node::from_fuzz_bytes(&bytes).unwrap();
+    ///
+    /// // The generated tree never changes outside of breaking releases.
+    /// #[rustfmt::skip]
+    /// let expected = Node::root().with_children(&[
+    ///     Node::new(true, 3).with_children(&[
+    ///         Node::new(true, 7).with_children(&[
+    ///             Node::new(false, 9),
+    ///         ]),
+    ///     ]),
+    ///     Node::new(true, 2),
+    /// ]);
+    /// assert_eq!(node, expected);
+    ///
+    /// let selected = Bot::new(true).select(&node, ToCompletion);
+    /// assert!(Brute::new(true).check_if_best(&node, selected.as_ref(), u32::MAX));
+    ///
+    /// assert_eq!(Node::from_fuzz_bytes(&[1, 2, 3]), None);
+    /// ```
+    /// [fuzz]: https://github.com/lcnr/rubot/tree/master/fuzz
+    #[cfg(feature = "testing")]
+    pub fn from_fuzz_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() >= 4 {
+            Some(Self::from_bytes(bytes))
+        } else {
+            None
+        }
+    }
+
     /// Sets the children of `self` to `children`.
     /// Previous children are forgotten.
     pub fn with_children(mut self, children: &[Node]) -> Self {